@@ -1,8 +1,11 @@
 use std::collections::HashSet;
 
 use ecow::eco_format;
-use typst_library::diag::{bail, error, At, SourceDiagnostic, SourceResult};
-use typst_library::foundations::{Array, Dict, Value};
+use typst_library::diag::{
+    bail, error, At, HintedStrResult, SourceDiagnostic, SourceResult,
+};
+use typst_library::foundations::{Array, Content, Dict, IntoValue, Str, Type, Value};
+use typst_library::layout::{Length, Rel};
 use typst_syntax::ast::{self, AstNode};
 
 use crate::{Access, Eval, Vm};
@@ -11,7 +14,7 @@ impl Eval for ast::LetBinding<'_> {
     type Output = Value;
 
     fn eval(self, vm: &mut Vm) -> SourceResult<Self::Output> {
-        let value = match self.init() {
+        let mut value = match self.init() {
             Some(expr) => expr.eval(vm)?,
             None => Value::None,
         };
@@ -19,6 +22,12 @@ impl Eval for ast::LetBinding<'_> {
             return Ok(Value::None);
         }
 
+        if let Some(annotation) = self.annotation() {
+            let ty = annotation.eval(vm)?.cast::<Type>().at(annotation.span())?;
+            let span = self.init().map_or(annotation.span(), |init| init.span());
+            value = coerce(value, ty).at(span)?;
+        }
+
         match self.kind() {
             ast::LetBindingKind::Normal(pattern) => destructure(vm, pattern, value)?,
             ast::LetBindingKind::Closure(ident) => vm.define(ident, value),
@@ -28,6 +37,25 @@ impl Eval for ast::LetBinding<'_> {
     }
 }
 
+/// Converts a value to the type it is annotated with. Like for function
+/// parameters, a value of a different type is accepted if it can be cast to
+/// the annotated type, e.g. an integer where a float is expected.
+fn coerce(value: Value, ty: Type) -> HintedStrResult<Value> {
+    if value.ty() == ty {
+        Ok(value)
+    } else if ty == Type::of::<f64>() {
+        value.cast::<f64>().map(IntoValue::into_value)
+    } else if ty == Type::of::<Rel<Length>>() {
+        value.cast::<Rel<Length>>().map(IntoValue::into_value)
+    } else if ty == Type::of::<Str>() {
+        value.cast::<Str>().map(IntoValue::into_value)
+    } else if ty == Type::of::<Content>() {
+        value.cast::<Content>().map(IntoValue::into_value)
+    } else {
+        Err(eco_format!("expected {}, found {}", ty, value.ty()).into())
+    }
+}
+
 impl Eval for ast::DestructAssignment<'_> {
     type Output = Value;
 
@@ -54,11 +54,9 @@ impl Eval for ast::FuncCall<'_> {
             .map_err(|err| hint_if_shadowed_std(vm, &self.callee(), err))
             .at(callee_span)?;
 
-        let point = || Tracepoint::Call(func.name().map(Into::into));
-        let f = || {
-            func.call(&mut vm.engine, vm.context, args)
-                .trace(vm.world(), point, span)
-        };
+        let world = vm.world();
+        let point = || Tracepoint::Call(Some(func.display_name(world)));
+        let f = || func.call(&mut vm.engine, vm.context, args).trace(world, point, span);
 
         // Stacker is broken on WASM.
         #[cfg(target_arch = "wasm32")]
@@ -517,6 +515,10 @@ impl<'a> CapturesVisitor<'a> {
             // A let expression contains a binding, but that binding is only
             // active after the body is evaluated.
             Some(ast::Expr::LetBinding(expr)) => {
+                if let Some(annotation) = expr.annotation() {
+                    self.visit(annotation.to_untyped());
+                }
+
                 if let Some(init) = expr.init() {
                     self.visit(init.to_untyped());
                 }
@@ -6,7 +6,8 @@ use typst_syntax::{Span, Spanned};
 
 use crate::diag::{bail, error, At, SourceDiagnostic, SourceResult, StrResult};
 use crate::foundations::{
-    cast, func, repr, scope, ty, Array, Dict, FromValue, IntoValue, Repr, Str, Value,
+    cast, func, repr, scope, ty, Array, Dict, FromValue, IntoValue, ParamInfo, Repr, Str,
+    Value,
 };
 
 /// Captured arguments to a function.
@@ -244,6 +245,53 @@ impl Args {
         }
     }
 
+    /// Whether the arguments are structurally equal, see
+    /// [`Value::structural_eq`].
+    pub fn structural_eq(&self, other: &Self) -> bool {
+        self.items.len() == other.items.len()
+            && self
+                .items
+                .iter()
+                .zip(&other.items)
+                .all(|(a, b)| a.name == b.name && a.value.v.structural_eq(&b.value.v))
+    }
+
+    /// Matches the arguments against a list of declared parameters.
+    ///
+    /// Returns a dictionary from parameter names to values, in the order of
+    /// the parameters. Positional parameters consume positional arguments in
+    /// order and variadic parameters collect all remaining positional
+    /// arguments into an array. Parameters that were not given fall back to
+    /// their default value or are omitted if they have none.
+    ///
+    /// Fails with a `missing argument` error if a required parameter was not
+    /// given and with an `unexpected argument` error if any argument is left
+    /// over. The values are not cast to the parameters' declared inputs.
+    pub fn match_params(mut self, params: &[ParamInfo]) -> SourceResult<Dict> {
+        let mut dict = Dict::new();
+        for param in params {
+            let value = if param.variadic {
+                Some(self.all::<Value>()?.into_iter().collect::<Array>().into_value())
+            } else if param.positional && param.named {
+                self.named_or_find::<Value>(param.name)?
+            } else if param.positional {
+                self.eat::<Value>()?
+            } else {
+                self.named::<Value>(param.name)?
+            };
+
+            let value = match value.or_else(|| param.default.map(|default| default())) {
+                Some(value) => value,
+                None if param.required => bail!(self.missing_argument(param.name)),
+                None => continue,
+            };
+
+            dict.insert(param.name.into(), value);
+        }
+        self.finish()?;
+        Ok(dict)
+    }
+
     /// Return an "unexpected argument" error if there is any remaining
     /// argument.
     pub fn finish(self) -> SourceResult<()> {
@@ -465,3 +513,75 @@ fn missing_key_no_default(key: ArgumentKey) -> EcoString {
         }
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::foundations::CastInfo;
+
+    fn param(name: &'static str, positional: bool, required: bool) -> ParamInfo {
+        ParamInfo {
+            name,
+            docs: "",
+            input: CastInfo::Any,
+            default: (!required).then_some(zero as fn() -> Value),
+            positional,
+            named: !positional,
+            variadic: false,
+            required,
+            settable: false,
+        }
+    }
+
+    fn zero() -> Value {
+        Value::Int(0)
+    }
+
+    fn args(pos: &[i64], named: &[(&str, i64)]) -> Args {
+        let mut args = Args::new(Span::detached(), pos.iter().copied());
+        for &(name, value) in named {
+            args.items.push(Arg {
+                span: Span::detached(),
+                name: Some(name.into()),
+                value: Spanned::new(value.into_value(), Span::detached()),
+            });
+        }
+        args
+    }
+
+    fn message(result: SourceResult<Dict>) -> EcoString {
+        result.unwrap_err()[0].message.clone()
+    }
+
+    #[test]
+    fn test_args_match_params() {
+        let params =
+            [param("x", true, true), param("y", true, false), param("z", false, false)];
+
+        // Valid constructions, with and without defaults.
+        let dict = args(&[1, 2], &[("z", 3)]).match_params(&params).unwrap();
+        assert_eq!(
+            dict.into_iter().collect::<Vec<_>>(),
+            [
+                ("x".into(), Value::Int(1)),
+                ("y".into(), Value::Int(2)),
+                ("z".into(), Value::Int(3)),
+            ]
+        );
+        let dict = args(&[1], &[]).match_params(&params).unwrap();
+        assert_eq!(dict.get("y").unwrap(), &Value::Int(0));
+        assert_eq!(dict.get("z").unwrap(), &Value::Int(0));
+
+        // Unknown named and excess positional arguments.
+        let result = args(&[1], &[("w", 4)]).match_params(&params);
+        assert_eq!(message(result), "unexpected argument: w");
+        let result = args(&[1, 2, 3], &[]).match_params(&params);
+        assert_eq!(message(result), "unexpected argument");
+
+        // Missing required positional argument.
+        let result = args(&[], &[("z", 3)]).match_params(&params);
+        assert_eq!(message(result), "missing argument: x");
+        let result = args(&[], &[("x", 1)]).match_params(&params);
+        assert_eq!(message(result), "the argument `x` is positional");
+    }
+}
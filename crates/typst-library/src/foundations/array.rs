@@ -1,15 +1,20 @@
 use std::cmp::Ordering;
+use std::collections::HashMap;
 use std::fmt::{Debug, Formatter};
+use std::hash::{DefaultHasher, Hasher};
 use std::num::{NonZeroI64, NonZeroUsize};
 use std::ops::{Add, AddAssign};
 
 use comemo::Tracked;
 use ecow::{eco_format, EcoString, EcoVec};
+use indexmap::IndexMap;
 use serde::{Deserialize, Serialize};
 use smallvec::SmallVec;
 use typst_syntax::{Span, Spanned};
 
-use crate::diag::{bail, At, HintedStrResult, SourceDiagnostic, SourceResult, StrResult};
+use crate::diag::{
+    bail, At, HintedStrResult, HintedString, SourceDiagnostic, SourceResult, StrResult,
+};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, func, ops, repr, scope, ty, Args, Bytes, CastInfo, Context, Dict, FromValue,
@@ -153,9 +158,19 @@ impl Array {
     /// value to an array, not for creation of an array from individual items. Use
     /// the array syntax `(1, 2, 3)` (or `(1,)` for a single-element array) instead.
     ///
+    /// - Arrays are returned unchanged.
+    /// - Bytes are converted to an array of integers.
+    /// - Versions are converted to an array of their components.
+    /// - Strings are split into their grapheme clusters, like with
+    ///   [`str.clusters`].
+    /// - Dictionaries are converted to an array of key-value pairs, like with
+    ///   [`dictionary.pairs`].
+    ///
     /// ```example
     /// #let hi = "Hello 😃"
-    /// #array(bytes(hi))
+    /// #array(bytes(hi)) \
+    /// #array(hi) \
+    /// #array((a: 1, b: 2))
     /// ```
     #[func(constructor)]
     pub fn construct(
@@ -426,6 +441,41 @@ impl Array {
         Ok(kept.into())
     }
 
+    /// Splits the array into the items for which the given function returns
+    /// true and those for which it returns false.
+    ///
+    /// Returns a pair of arrays `(matching, non-matching)`. Both keep the
+    /// original order of the items.
+    ///
+    /// ```example
+    /// #let (even, odd) = range(10).partition(calc.even)
+    /// #even \
+    /// #odd
+    /// ```
+    #[func]
+    pub fn partition(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to apply to each item. Must return a boolean.
+        test: Func,
+    ) -> SourceResult<Array> {
+        let mut matching = EcoVec::new();
+        let mut rest = EcoVec::new();
+        for item in self {
+            if test
+                .call(engine, context, [item.clone()])?
+                .cast::<bool>()
+                .at(test.span())?
+            {
+                matching.push(item);
+            } else {
+                rest.push(item);
+            }
+        }
+        Ok(array![Array::from(matching), Array::from(rest)])
+    }
+
     /// Produces a new array in which all items from the original one were
     /// transformed with the given function.
     #[func]
@@ -585,46 +635,83 @@ impl Array {
     }
 
     /// Sums all items (works for all types that can be added).
+    ///
+    /// ```example
+    /// #(1, 2, 3).sum() \
+    /// #().sum()
+    /// ```
     #[func]
     pub fn sum(
         self,
-        /// What to return if the array is empty. Must be set if the array can
-        /// be empty.
+        /// What to return if the array is empty.
         #[named]
-        default: Option<Value>,
+        #[default(Value::Int(0))]
+        default: Value,
     ) -> HintedStrResult<Value> {
         let mut iter = self.into_iter();
-        let mut acc = iter
-            .next()
-            .or(default)
-            .ok_or("cannot calculate sum of empty array with no default")?;
-        for item in iter {
-            acc = ops::add(acc, item)?;
+        let Some(mut acc) = iter.next() else { return Ok(default) };
+        for (i, item) in iter.enumerate() {
+            acc = ops::add(acc, item).map_err(|err| at_index("add", i + 1, err))?;
         }
         Ok(acc)
     }
 
     /// Calculates the product all items (works for all types that can be
     /// multiplied).
+    ///
+    /// ```example
+    /// #(1, 2, 3, 4).product() \
+    /// #().product()
+    /// ```
     #[func]
     pub fn product(
         self,
-        /// What to return if the array is empty. Must be set if the array can
-        /// be empty.
+        /// What to return if the array is empty.
         #[named]
-        default: Option<Value>,
+        #[default(Value::Int(1))]
+        default: Value,
     ) -> HintedStrResult<Value> {
         let mut iter = self.into_iter();
-        let mut acc = iter
-            .next()
-            .or(default)
-            .ok_or("cannot calculate product of empty array with no default")?;
-        for item in iter {
-            acc = ops::mul(acc, item)?;
+        let Some(mut acc) = iter.next() else { return Ok(default) };
+        for (i, item) in iter.enumerate() {
+            acc = ops::mul(acc, item).map_err(|err| at_index("multiply", i + 1, err))?;
         }
         Ok(acc)
     }
 
+    /// Calculates the arithmetic mean of all items. The items must be numbers,
+    /// lengths, angles, ratios, fractions, or durations that can be added
+    /// together.
+    ///
+    /// ```example
+    /// #(1, 2, 3, 4).mean() \
+    /// #(1pt, 2pt, 6pt).mean()
+    /// ```
+    #[func]
+    pub fn mean(self) -> HintedStrResult<Value> {
+        if self.is_empty() {
+            return Err("cannot calculate mean of empty array".into());
+        }
+        for (i, item) in self.iter().enumerate() {
+            if !matches!(
+                item,
+                Value::Int(_)
+                    | Value::Float(_)
+                    | Value::Decimal(_)
+                    | Value::Length(_)
+                    | Value::Angle(_)
+                    | Value::Ratio(_)
+                    | Value::Relative(_)
+                    | Value::Fraction(_)
+                    | Value::Duration(_)
+            ) {
+                bail!("cannot average value of type {} at index {i}", item.ty());
+            }
+        }
+        let len = self.len() as i64;
+        ops::div(self.sum(Value::Int(0))?, Value::Int(len))
+    }
+
     /// Whether the given function returns `{true}` for any item in the array.
     #[func]
     pub fn any(
@@ -664,12 +751,17 @@ impl Array {
     /// Combine all nested arrays into a single flat one.
     #[func]
     pub fn flatten(self) -> Array {
+        // Use an explicit stack instead of recursion, so that flattening
+        // itself doesn't need a native stack frame per nesting level.
         let mut flat = EcoVec::with_capacity(self.0.len());
-        for item in self {
-            if let Value::Array(nested) = item {
-                flat.extend(nested.flatten());
-            } else {
-                flat.push(item);
+        let mut stack = vec![self.into_iter()];
+        while let Some(iter) = stack.last_mut() {
+            match iter.next() {
+                Some(Value::Array(nested)) => stack.push(nested.into_iter()),
+                Some(item) => flat.push(item),
+                None => {
+                    stack.pop();
+                }
             }
         }
         flat.into()
@@ -962,6 +1054,39 @@ impl Array {
         }
     }
 
+    /// Groups the items of the array by a key.
+    ///
+    /// Returns a dictionary that maps each key to an array of the items with
+    /// that key. The groups are ordered by the first occurrence of their key
+    /// and the items within each group keep their original order. Keys that
+    /// are not strings are converted with [`repr`].
+    ///
+    /// ```example
+    /// #let words = ("apple", "avocado", "banana", "cherry", "blueberry")
+    /// #words.group-by(word => word.first())
+    /// ```
+    #[func]
+    pub fn group_by(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        /// The function to compute the key of each item.
+        key: Func,
+    ) -> SourceResult<Dict> {
+        let mut groups: IndexMap<Str, EcoVec<Value>> = IndexMap::new();
+        for item in self {
+            let name = match key.call(engine, context, [item.clone()])? {
+                Value::Str(name) => name,
+                other => other.repr().into(),
+            };
+            groups.entry(name).or_default().push(item);
+        }
+        Ok(groups
+            .into_iter()
+            .map(|(name, items)| (name, Array::from(items).into_value()))
+            .collect())
+    }
+
     /// Deduplicates all items in the array.
     ///
     /// Returns a new array with all duplicate items removed. Only the first
@@ -1010,6 +1135,59 @@ impl Array {
         Ok(Self(out))
     }
 
+    /// Combines this array with another one, treating both as sets.
+    ///
+    /// Returns a new array with all items that occur in either array. Only
+    /// the first occurrence of each item is kept.
+    ///
+    /// ```example
+    /// #(1, 2, 2, 3).union((3, 4, 1))
+    /// ```
+    #[func]
+    pub fn union(
+        self,
+        /// The array to combine with.
+        other: Array,
+    ) -> Array {
+        collect_set(self.into_iter().chain(other), |_| true)
+    }
+
+    /// Intersects this array with another one, treating both as sets.
+    ///
+    /// Returns a new array with all items of this array that also occur in the
+    /// other one. Only the first occurrence of each item is kept.
+    ///
+    /// ```example
+    /// #(1, 2, 2, 3).intersection((3, 2, 5))
+    /// ```
+    #[func]
+    pub fn intersection(
+        self,
+        /// The array to intersect with.
+        other: Array,
+    ) -> Array {
+        let other = ValueSet::from_values(&other);
+        collect_set(self.into_iter(), |value| other.contains(value))
+    }
+
+    /// Subtracts another array from this one, treating both as sets.
+    ///
+    /// Returns a new array with all items of this array that do not occur in
+    /// the other one. Only the first occurrence of each item is kept.
+    ///
+    /// ```example
+    /// #(1, 2, 2, 3).difference((3, 5))
+    /// ```
+    #[func]
+    pub fn difference(
+        self,
+        /// The array whose items are removed.
+        other: Array,
+    ) -> Array {
+        let other = ValueSet::from_values(&other);
+        collect_set(self.into_iter(), |value| !other.contains(value))
+    }
+
     /// Converts an array of pairs into a dictionary.
     /// The first value of each pair is the key, the second the value.
     ///
@@ -1079,7 +1257,9 @@ cast! {
     ToArray,
     v: Array => Self(v),
     v: Bytes => Self(v.iter().map(|&b| Value::Int(b.into())).collect()),
-    v: Version => Self(v.values().iter().map(|&v| Value::Int(v as i64)).collect())
+    v: Version => Self(v.values().iter().map(|&v| Value::Int(v as i64)).collect()),
+    v: Str => Self(v.clusters()),
+    v: Dict => Self(v.pairs()),
 }
 
 impl Debug for Array {
@@ -1259,6 +1439,76 @@ impl<T> Default for OneOrMultiple<T> {
     }
 }
 
+/// Collects the values that pass the filter into an array without duplicates,
+/// preserving the order of first occurrence.
+///
+/// Values are bucketed by their canonical hash, so this is linear in the
+/// number of values. Within a bucket, values are still compared with
+/// `ops::equal` to guard against hash collisions.
+fn collect_set(
+    values: impl IntoIterator<Item = Value>,
+    filter: impl Fn(&Value) -> bool,
+) -> Array {
+    let mut out = EcoVec::new();
+    let mut seen = ValueSet::default();
+    for value in values {
+        if filter(&value) && seen.insert(&value) {
+            out.push(value);
+        }
+    }
+    Array(out)
+}
+
+/// A set of values, comparing them with `ops::equal`.
+#[derive(Default)]
+struct ValueSet(HashMap<u64, SmallVec<[Value; 1]>>);
+
+impl ValueSet {
+    /// Creates a set containing the given values.
+    fn from_values<'a>(values: impl IntoIterator<Item = &'a Value>) -> Self {
+        let mut set = Self::default();
+        for value in values {
+            set.insert(value);
+        }
+        set
+    }
+
+    /// Whether the set contains a value equal to the given one.
+    fn contains(&self, value: &Value) -> bool {
+        self.0
+            .get(&canonical_hash(value))
+            .is_some_and(|bucket| bucket.iter().any(|seen| ops::equal(seen, value)))
+    }
+
+    /// Inserts the value, returning whether it wasn't yet contained.
+    fn insert(&mut self, value: &Value) -> bool {
+        let bucket = self.0.entry(canonical_hash(value)).or_default();
+        if bucket.iter().any(|seen| ops::equal(seen, value)) {
+            return false;
+        }
+        bucket.push(value.clone());
+        true
+    }
+}
+
+/// Hashes a value with [`Value::hash_canonical`].
+fn canonical_hash(value: &Value) -> u64 {
+    let mut hasher = DefaultHasher::new();
+    value.hash_canonical(&mut hasher);
+    hasher.finish()
+}
+
+/// Prefixes an error from combining array items with the offending item's
+/// index.
+#[cold]
+fn at_index(verb: &str, i: usize, err: HintedString) -> HintedString {
+    HintedString::new(eco_format!(
+        "cannot {verb} element at index {i}: {}",
+        err.message()
+    ))
+    .with_hints(err.hints().iter().cloned())
+}
+
 /// The error message when the array is empty.
 #[cold]
 fn array_is_empty() -> EcoString {
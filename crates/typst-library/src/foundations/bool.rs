@@ -1,21 +1,65 @@
 use ecow::EcoString;
 
-use crate::foundations::{ty, Repr};
+use crate::diag::{bail, StrResult};
+use crate::foundations::{func, scope, ty, Repr, Value};
 
 /// A type with two states.
 ///
 /// The boolean type has two values: `{true}` and `{false}`. It denotes whether
 /// something is active or enabled.
 ///
+/// Conditions in `{if}` and `{while}` must be booleans. Other values are not
+/// implicitly treated as true or false. To test a value for truthiness, convert
+/// it explicitly with the [`bool`]($bool) constructor.
+///
 /// # Example
 /// ```example
 /// #false \
 /// #true \
 /// #(1 < 2)
 /// ```
-#[ty(cast, title = "Boolean")]
+#[ty(scope, cast, title = "Boolean")]
 type bool;
 
+#[scope]
+impl bool {
+    /// Converts a value to a boolean.
+    ///
+    /// - Booleans are returned unchanged.
+    /// - `{none}` is `{false}`.
+    /// - Integers, floats, and decimals are `{true}` if they are nonzero.
+    /// - Strings, arrays, dictionaries, and bytes are `{true}` if they are
+    ///   non-empty.
+    ///
+    /// Raises an error for all other values, for example functions or content.
+    ///
+    /// ```example
+    /// #bool(none) \
+    /// #bool(0) \
+    /// #bool(2.5) \
+    /// #bool("") \
+    /// #bool((1, 2))
+    /// ```
+    #[func(constructor)]
+    pub fn construct(
+        /// The value that should be converted to a boolean.
+        value: Value,
+    ) -> StrResult<bool> {
+        Ok(match value {
+            Value::Bool(v) => v,
+            Value::None => false,
+            Value::Int(v) => v != 0,
+            Value::Float(v) => v != 0.0,
+            Value::Decimal(v) => !v.is_zero(),
+            Value::Str(v) => !v.is_empty(),
+            Value::Array(v) => !v.is_empty(),
+            Value::Dict(v) => !v.is_empty(),
+            Value::Bytes(v) => !v.is_empty(),
+            v => bail!("cannot convert {} to boolean", v.ty()),
+        })
+    }
+}
+
 impl Repr for bool {
     fn repr(&self) -> EcoString {
         match self {
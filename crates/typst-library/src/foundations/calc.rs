@@ -51,10 +51,12 @@ pub fn module() -> Module {
     scope.define_func::<rem_euclid>();
     scope.define_func::<quo>();
     scope.define_func::<norm>();
-    scope.define("inf", f64::INFINITY);
-    scope.define("pi", std::f64::consts::PI);
-    scope.define("tau", std::f64::consts::TAU);
-    scope.define("e", std::f64::consts::E);
+    scope.define_consts([
+        ("inf", Value::Float(f64::INFINITY)),
+        ("pi", Value::Float(std::f64::consts::PI)),
+        ("tau", Value::Float(std::f64::consts::TAU)),
+        ("e", Value::Float(std::f64::consts::E)),
+    ]);
     Module::new("calc", scope)
 }
 
@@ -166,8 +166,13 @@ impl Dict {
     /// dictionary-like value to a dictionary, not for creation of a dictionary
     /// from individual pairs. Use the dictionary syntax `(key: value)` instead.
     ///
+    /// - Dictionaries are returned unchanged.
+    /// - Modules are converted to a dictionary of their definitions.
+    /// - Arrays of key-value pairs are converted like with [`array.to-dict`].
+    ///
     /// ```example
-    /// #dictionary(sys).at("version")
+    /// #dictionary(sys).at("version") \
+    /// #dictionary((("a", 1), ("b", 2)))
     /// ```
     #[func(constructor)]
     pub fn construct(
@@ -254,6 +259,22 @@ impl Dict {
             .map(|(k, v)| Value::Array(array![k.clone(), v.clone()]))
             .collect()
     }
+
+    /// Returns a copy of the dictionary with its pairs sorted by key.
+    ///
+    /// Keys are compared by their Unicode codepoints. This is useful to
+    /// iterate over a dictionary in a fixed order that does not depend on how
+    /// it was built.
+    ///
+    /// ```example
+    /// #let dict = (b: 1, c: 2, a: 3)
+    /// #dict.sorted().keys()
+    /// ```
+    #[func]
+    pub fn sorted(mut self) -> Dict {
+        Arc::make_mut(&mut self.0).sort_keys();
+        self
+    }
 }
 
 /// A value that can be cast to dictionary.
@@ -261,12 +282,14 @@ pub struct ToDict(Dict);
 
 cast! {
     ToDict,
+    v: Dict => Self(v),
     v: Module => Self(v
         .scope()
         .iter()
         .map(|(k, b)| (Str::from(k.clone()), b.read().clone()))
         .collect()
     ),
+    v: Array => Self(v.to_dict()?),
 }
 
 impl Debug for Dict {
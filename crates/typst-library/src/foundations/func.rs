@@ -6,15 +6,17 @@ use std::sync::{Arc, LazyLock};
 
 use comemo::{Tracked, TrackedMut};
 use ecow::{eco_format, EcoString};
-use typst_syntax::{ast, Span, SyntaxNode};
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
+use typst_syntax::{ast, Span, SyntaxKind, SyntaxNode};
 use typst_utils::{singleton, LazyHash, Static};
 
 use crate::diag::{bail, At, DeprecationSink, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
-    cast, repr, scope, ty, Args, Bytes, CastInfo, Content, Context, Element, IntoArgs,
-    PluginFunc, Scope, Selector, Type, Value,
+    cast, repr, scope, ty, Args, Array, Binding, Bytes, Capturer, CastInfo, Content,
+    Context, Element, IntoArgs, PluginFunc, Scope, Selector, Type, Value,
 };
+use crate::World;
 
 /// A mapping from argument values to a return value.
 ///
@@ -124,6 +126,21 @@ use crate::foundations::{
 /// once?
 /// ```
 ///
+/// # Comparing functions
+/// Functions can be compared with `{==}`, which also lets you
+/// [deduplicate]($array.dedup) them. Two built-in functions are equal if they
+/// are the same function. Two user-defined functions are equal if they stem
+/// from the same place in the source code and have captured equal values from
+/// their surroundings. Functions created with [`with`]($function.with) are equal if
+/// they pre-apply equal arguments to equal functions.
+///
+/// ```example
+/// #let adders = range(3).map(i => x => x + i)
+/// #let idents = range(3).map(i => x => x)
+/// #adders.dedup().len() \
+/// #idents.dedup().len()
+/// ```
+///
 /// # Note on function purity
 /// In Typst, all functions are _pure._ This means that for the same
 /// arguments, they always return the same result. They cannot "remember" things to
@@ -171,6 +188,62 @@ impl Func {
         }
     }
 
+    /// A name for the function that is suitable for display in diagnostics.
+    ///
+    /// Unlike [`name`](Self::name), this is always available: Anonymous
+    /// closures are named after the location of their definition (e.g.
+    /// `<closure@12:4>` for a closure defined in line 12, column 4) and
+    /// functions with pre-applied arguments are named after the function they
+    /// wrap (e.g. `f.with(..)`). Composed functions are named after the
+    /// `compose` call that created them (e.g. `f.compose(g)`).
+    pub fn display_name(&self, world: Tracked<dyn World + '_>) -> EcoString {
+        self.display_name_with(&|span| {
+            let source = world.source(span.id()?).ok()?;
+            let offset = source.range(span)?.start;
+            Some((source.byte_to_line(offset)?, source.byte_to_column(offset)?))
+        })
+    }
+
+    /// Implementation of [`display_name`](Self::display_name) with a function
+    /// that resolves a span to its zero-based line and column.
+    fn display_name_with(
+        &self,
+        locate: &dyn Fn(Span) -> Option<(usize, usize)>,
+    ) -> EcoString {
+        match &self.repr {
+            // Composed functions are the hidden `composed` function with the
+            // functions pre-applied. Name them like the `compose` call instead.
+            Repr::With(with) if with.0 == composed::data() => {
+                let names: Vec<EcoString> = with
+                    .1
+                    .items
+                    .iter()
+                    .filter_map(|arg| arg.value.v.clone().cast::<Vec<Func>>().ok())
+                    .flatten()
+                    .map(|func| func.display_name_with(locate))
+                    .collect();
+                match names.split_first() {
+                    Some((first, rest)) => {
+                        eco_format!("{first}.compose({})", rest.join(", "))
+                    }
+                    None => "composed".into(),
+                }
+            }
+            Repr::With(with) => {
+                eco_format!("{}.with(..)", with.0.display_name_with(locate))
+            }
+            Repr::Closure(closure) if closure.name().is_none() => {
+                match locate(closure.node.span()) {
+                    Some((line, column)) => {
+                        eco_format!("<closure@{}:{}>", line + 1, column + 1)
+                    }
+                    None => "<closure>".into(),
+                }
+            }
+            _ => self.name().unwrap_or("<closure>").into(),
+        }
+    }
+
     /// The function's title case name, for use in documentation (e.g. `Minimum`).
     ///
     /// Returns `None` if this is a closure.
@@ -353,6 +426,18 @@ impl Func {
         }
         self
     }
+
+    /// Whether the functions are structurally equal, see
+    /// [`Value::structural_eq`].
+    pub fn structural_eq(&self, other: &Self) -> bool {
+        match (&self.repr, &other.repr) {
+            (Repr::Closure(a), Repr::Closure(b)) => a.structural_eq(b),
+            (Repr::With(a), Repr::With(b)) => {
+                a.0.structural_eq(&b.0) && a.1.structural_eq(&b.1)
+            }
+            _ => self == other,
+        }
+    }
 }
 
 #[scope]
@@ -374,6 +459,53 @@ impl Func {
         }
     }
 
+    /// Composes this function with other functions.
+    ///
+    /// The resulting function takes a single argument and passes it through
+    /// the functions from right to left: `{f.compose(g, h)}` behaves like
+    /// `{x => f(g(h(x)))}`.
+    ///
+    /// ```example
+    /// #let double(x) = 2 * x
+    /// #let inc(x) = x + 1
+    /// #double.compose(inc)(3) \
+    /// #function.compose(inc, double)(3)
+    /// ```
+    #[func]
+    pub fn compose(
+        self,
+        /// The functions to apply before this one. The last one is applied
+        /// first.
+        #[variadic]
+        others: Vec<Func>,
+    ) -> Func {
+        let span = self.span;
+        let funcs: Array = std::iter::once(self).chain(others).map(Value::Func).collect();
+        composed::func().with(&mut Args::new(span, [funcs])).spanned(span)
+    }
+
+    /// Calls this function with the items of an array as positional
+    /// arguments.
+    ///
+    /// `{f.apply((a, b))}` behaves like `{f(a, b)}` and `{f(..(a, b))}`.
+    ///
+    /// ```example
+    /// #let add(x, y) = x + y
+    /// #add.apply((1, 2)) \
+    /// #function.apply(calc.max, (3, 7, 5))
+    /// ```
+    #[func]
+    pub fn apply(
+        self,
+        engine: &mut Engine,
+        context: Tracked<Context>,
+        span: Span,
+        /// The positional arguments to call the function with.
+        arguments: Array,
+    ) -> SourceResult<Value> {
+        self.call(engine, context, Args::new(span, arguments))
+    }
+
     /// Returns a selector that filters for elements belonging to this function
     /// whose fields have the values of the given arguments.
     ///
@@ -416,6 +548,25 @@ impl Func {
     }
 }
 
+/// Passes a value through composed functions from right to left.
+///
+/// Backs [`Func::compose`] through pre-applied arguments since native
+/// functions can't capture.
+#[func]
+fn composed(
+    engine: &mut Engine,
+    context: Tracked<Context>,
+    /// The composed functions.
+    funcs: Vec<Func>,
+    /// The value to pass through the functions.
+    value: Value,
+) -> SourceResult<Value> {
+    funcs
+        .iter()
+        .rev()
+        .try_fold(value, |value, func| func.call(engine, context, [value]))
+}
+
 impl Debug for Func {
     fn fmt(&self, f: &mut Formatter) -> fmt::Result {
         write!(f, "Func({})", self.name().unwrap_or(".."))
@@ -562,9 +713,251 @@ impl Closure {
     pub fn name(&self) -> Option<&str> {
         self.node.cast::<ast::Closure>()?.name().map(|ident| ident.as_str())
     }
+
+    /// Whether the closures are structurally equal, that is, whether they have
+    /// the same syntax (ignoring spans), defaults, and captured values.
+    pub fn structural_eq(&self, other: &Self) -> bool {
+        self.num_pos_params == other.num_pos_params
+            && self.node.spanless_eq(&other.node)
+            && self.defaults.len() == other.defaults.len()
+            && self
+                .defaults
+                .iter()
+                .zip(&other.defaults)
+                .all(|(a, b)| a.structural_eq(b))
+            && self.captured.iter().count() == other.captured.iter().count()
+            && self.captured.iter().all(|(name, a)| {
+                other
+                    .captured
+                    .get(name)
+                    .is_some_and(|b| a.read().structural_eq(b.read()))
+            })
+    }
+}
+
+/// Serializes the closure's source code together with its default values and
+/// captured variables, which must all be plain data (none, booleans, numbers,
+/// strings, arrays, and dictionaries). The standard library is not part
+/// of the serialized form and is provided by the world the deserialized closure
+/// is called in.
+impl Serialize for Closure {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let Some(closure) = self.node.cast::<ast::Closure>() else {
+            return Err(ser::Error::custom("cannot serialize context expression"));
+        };
+
+        // Named closures are only valid syntax as part of a let binding.
+        let mut source = EcoString::new();
+        if closure.name().is_some() {
+            source.push_str("let ");
+        }
+        source.push_str(&self.node.clone().into_text());
+
+        for value in &self.defaults {
+            check_serializable(value).map_err(ser::Error::custom)?;
+        }
+
+        let mut captured = vec![];
+        for (name, binding) in self.captured.iter() {
+            let value = binding.read();
+            check_serializable(value).map_err(|err| {
+                ser::Error::custom(eco_format!("captured variable `{name}`: {err}"))
+            })?;
+            captured.push((name.clone(), value.clone()));
+        }
+
+        SerializedClosure { source, defaults: self.defaults.clone(), captured }
+            .serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Closure {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = SerializedClosure::deserialize(deserializer)?;
+        let root = typst_syntax::parse_code(&data.source);
+        let node = Some(&root)
+            .filter(|root| !root.erroneous())
+            .and_then(find_closure)
+            .ok_or_else(|| de::Error::custom("invalid closure source"))?;
+
+        // Calling the closure relies on there being one default per named
+        // parameter, so the payload must agree with the parameter list.
+        let params = node.cast::<ast::Closure>().unwrap().params();
+        let count = |f: fn(&ast::Param) -> bool| params.children().filter(f).count();
+        let num_pos_params = count(|p| matches!(p, ast::Param::Pos(_)));
+        let num_named_params = count(|p| matches!(p, ast::Param::Named(_)));
+        if data.defaults.len() != num_named_params {
+            return Err(de::Error::custom(eco_format!(
+                "expected {num_named_params} default values, found {}",
+                data.defaults.len(),
+            )));
+        }
+
+        let mut captured = Scope::new();
+        for (name, value) in data.captured {
+            let binding = Binding::new(value, Span::detached());
+            captured.bind(name, binding.capture(Capturer::Function));
+        }
+
+        Ok(Self {
+            node,
+            defaults: data.defaults,
+            captured,
+            num_pos_params,
+        })
+    }
+}
+
+/// The serialized form of a [`Closure`].
+#[derive(Serialize, Deserialize)]
+struct SerializedClosure {
+    source: EcoString,
+    defaults: Vec<Value>,
+    captured: Vec<(EcoString, Value)>,
+}
+
+/// Ensures that a value survives a serialization round trip unchanged.
+pub(super) fn check_serializable(value: &Value) -> StrResult<()> {
+    match value {
+        Value::None
+        | Value::Bool(_)
+        | Value::Int(_)
+        | Value::Float(_)
+        | Value::Str(_) => Ok(()),
+        Value::Array(array) => array.iter().try_for_each(check_serializable),
+        Value::Dict(dict) => dict.iter().try_for_each(|(_, v)| check_serializable(v)),
+        other => bail!("cannot serialize value of type {}", other.ty()),
+    }
+}
+
+/// Finds the outermost closure in a syntax tree.
+fn find_closure(node: &SyntaxNode) -> Option<SyntaxNode> {
+    if node.kind() == SyntaxKind::Closure {
+        return Some(node.clone());
+    }
+    node.children().find_map(find_closure)
 }
 
 cast! {
     Closure,
     self => Value::Func(self.into()),
 }
+
+/// Creates a closure from the `index`-th closure expression in the source,
+/// without defaults or captured values.
+#[cfg(test)]
+pub(crate) fn closure_from(source: &typst_syntax::Source, index: usize) -> Closure {
+    fn collect(node: &SyntaxNode, out: &mut Vec<SyntaxNode>) {
+        if node.kind() == SyntaxKind::Closure {
+            out.push(node.clone());
+        }
+        for child in node.children() {
+            collect(child, out);
+        }
+    }
+
+    let mut nodes = vec![];
+    collect(source.root(), &mut nodes);
+    let node = nodes.swap_remove(index);
+    let num_pos_params = node
+        .cast::<ast::Closure>()
+        .unwrap()
+        .params()
+        .children()
+        .filter(|p| matches!(p, ast::Param::Pos(_)))
+        .count();
+    Closure {
+        node,
+        defaults: vec![],
+        captured: Scope::new(),
+        num_pos_params,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use typst_syntax::Source;
+
+    use super::*;
+    use crate::foundations::{panic, IntoValue};
+
+    #[test]
+    fn test_func_display_name() {
+        let source = Source::detached("#let f(x) = x\n#let g = x => x");
+        let locate = |span| {
+            let offset = source.range(span)?.start;
+            Some((source.byte_to_line(offset)?, source.byte_to_column(offset)?))
+        };
+
+        let native = panic::func();
+        assert_eq!(native.display_name_with(&locate), "panic");
+
+        // A named and an anonymous closure.
+        let named = Func::from(closure_from(&source, 0));
+        let anonymous = Func::from(closure_from(&source, 1));
+        assert_eq!(named.display_name_with(&locate), "f");
+        assert_eq!(anonymous.display_name_with(&locate), "<closure@2:10>");
+        assert_eq!(anonymous.display_name_with(&|_| None), "<closure>");
+
+        // Partial application.
+        let partial = anonymous.clone().with(&mut Args::new(Span::detached(), [1]));
+        assert_eq!(partial.display_name_with(&locate), "<closure@2:10>.with(..)");
+        let partial = native.clone().with(&mut Args::new(Span::detached(), ["boom"]));
+        assert_eq!(partial.display_name_with(&locate), "panic.with(..)");
+
+        // Composition.
+        let composed = named.compose(vec![anonymous, native]);
+        assert_eq!(
+            composed.display_name_with(&locate),
+            "f.compose(<closure@2:10>, panic)"
+        );
+        let partial = composed.with(&mut Args::new(Span::detached(), [1]));
+        assert_eq!(
+            partial.display_name_with(&locate),
+            "f.compose(<closure@2:10>, panic).with(..)"
+        );
+    }
+
+    #[test]
+    fn test_closure_serialize_round_trip() {
+        let source = Source::detached("#let f(x, y: 2) = x + y + data.len()");
+        let mut closure = closure_from(&source, 0);
+        closure.defaults = vec![Value::Int(2)];
+        closure
+            .captured
+            .define("data", Array::from_iter([1.into_value(), "a".into_value()]));
+
+        let json = serde_json::to_string(&closure).unwrap();
+        let restored: Closure = serde_json::from_str(&json).unwrap();
+        assert!(restored.structural_eq(&closure));
+        assert_eq!(restored.name(), Some("f"));
+        assert_eq!(restored.num_pos_params, 1);
+        assert!(restored.captured.get("data").unwrap().clone().write().is_err());
+
+        // Captured functions cannot be serialized.
+        let mut closure = restored;
+        closure.captured.define("g", panic::func());
+        let err = serde_json::to_string(&closure).unwrap_err();
+        assert_eq!(
+            err.to_string(),
+            "captured variable `g`: cannot serialize value of type function"
+        );
+    }
+
+    #[test]
+    fn test_closure_deserialize_mismatched_defaults() {
+        let json = r#"{"source":"(x, y: 2, z: 3) => x","defaults":[2],"captured":[]}"#;
+        let err = serde_json::from_str::<Closure>(json).unwrap_err();
+        assert!(err.to_string().starts_with("expected 2 default values, found 1"));
+
+        let json = r#"{"source":"(x, ..rest) => x","defaults":[],"captured":[]}"#;
+        let closure: Closure = serde_json::from_str(json).unwrap();
+        assert_eq!(closure.num_pos_params, 1);
+    }
+}
@@ -27,6 +27,7 @@ mod module;
 mod none;
 #[path = "plugin.rs"]
 mod plugin_;
+mod rng;
 mod scope;
 mod selector;
 mod str;
@@ -59,6 +60,7 @@ pub use self::module::*;
 pub use self::none::*;
 pub use self::plugin_::*;
 pub use self::repr::Repr;
+pub use self::rng::*;
 pub use self::scope::*;
 pub use self::selector::*;
 pub use self::str::*;
@@ -109,7 +111,10 @@ pub(super) fn define(global: &mut Scope, inputs: Dict, features: &Features) {
     global.define_type::<Symbol>();
     global.define_type::<Duration>();
     global.define_type::<Version>();
+    global.define_type::<Rng>();
     global.define_func::<repr::repr>();
+    global.define_func::<repr::pretty>();
+    global.define_func::<repr::inspect>();
     global.define_func::<panic>();
     global.define_func::<assert>();
     global.define_func::<eval>();
@@ -118,7 +123,7 @@ pub(super) fn define(global: &mut Scope, inputs: Dict, features: &Features) {
         global.define_func::<target>();
     }
     global.define("calc", calc::module());
-    global.define("sys", sys::module(inputs));
+    global.define("sys", sys::module(inputs, features));
     global.reset_category();
 }
 
@@ -7,7 +7,7 @@ use typst_utils::Numeric;
 
 use crate::diag::{bail, HintedStrResult, StrResult};
 use crate::foundations::{
-    format_str, Datetime, IntoValue, Regex, Repr, SymbolElem, Value,
+    format_str, Datetime, Decimal, IntoValue, Regex, Repr, SymbolElem, Value,
 };
 use crate::layout::{Alignment, Length, Rel};
 use crate::text::TextElem;
@@ -20,6 +20,40 @@ macro_rules! mismatch {
     };
 }
 
+/// Two numbers promoted to a common type, see [`promote`].
+#[derive(Debug, Copy, Clone, PartialEq)]
+pub enum Promoted {
+    /// Two integers.
+    Int(i64, i64),
+    /// Two floats.
+    Float(f64, f64),
+    /// Two decimals.
+    Decimal(Decimal, Decimal),
+}
+
+/// Promote two numbers to a common type for arithmetic and comparisons.
+///
+/// - Two integers stay integers.
+/// - An integer and a float are promoted to floats.
+/// - An integer and a decimal are promoted to decimals.
+///
+/// Returns `None` if either value is not a number or for a float and a decimal,
+/// which are never mixed implicitly because the conversion would be lossy in
+/// either direction.
+pub fn promote(lhs: &Value, rhs: &Value) -> Option<Promoted> {
+    use Value::*;
+    Some(match (lhs, rhs) {
+        (&Int(a), &Int(b)) => Promoted::Int(a, b),
+        (&Int(a), &Float(b)) => Promoted::Float(a as f64, b),
+        (&Float(a), &Int(b)) => Promoted::Float(a, b as f64),
+        (&Float(a), &Float(b)) => Promoted::Float(a, b),
+        (&Int(a), &Decimal(b)) => Promoted::Decimal(a.into(), b),
+        (&Decimal(a), &Int(b)) => Promoted::Decimal(a, b.into()),
+        (&Decimal(a), &Decimal(b)) => Promoted::Decimal(a, b),
+        _ => return None,
+    })
+}
+
 /// Join a value with another value.
 pub fn join(lhs: Value, rhs: Value) -> StrResult<Value> {
     use Value::*;
@@ -90,26 +124,18 @@ pub fn neg(value: Value) -> HintedStrResult<Value> {
 /// Compute the sum of two values.
 pub fn add(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
     use Value::*;
+    if let Some(numbers) = promote(&lhs, &rhs) {
+        return Ok(match numbers {
+            Promoted::Int(a, b) => Int(a.checked_add(b).ok_or_else(too_large)?),
+            Promoted::Float(a, b) => Float(a + b),
+            Promoted::Decimal(a, b) => Decimal(a.checked_add(b).ok_or_else(too_large)?),
+        });
+    }
+
     Ok(match (lhs, rhs) {
         (a, None) => a,
         (None, b) => b,
 
-        (Int(a), Int(b)) => Int(a.checked_add(b).ok_or_else(too_large)?),
-        (Int(a), Float(b)) => Float(a as f64 + b),
-        (Float(a), Int(b)) => Float(a + b as f64),
-        (Float(a), Float(b)) => Float(a + b),
-
-        (Decimal(a), Decimal(b)) => Decimal(a.checked_add(b).ok_or_else(too_large)?),
-        (Decimal(a), Int(b)) => Decimal(
-            a.checked_add(crate::foundations::Decimal::from(b))
-                .ok_or_else(too_large)?,
-        ),
-        (Int(a), Decimal(b)) => Decimal(
-            crate::foundations::Decimal::from(a)
-                .checked_add(b)
-                .ok_or_else(too_large)?,
-        ),
-
         (Angle(a), Angle(b)) => Angle(a + b),
 
         (Length(a), Length(b)) => Length(a + b),
@@ -174,23 +200,15 @@ pub fn add(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
 /// Compute the difference of two values.
 pub fn sub(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
     use Value::*;
-    Ok(match (lhs, rhs) {
-        (Int(a), Int(b)) => Int(a.checked_sub(b).ok_or_else(too_large)?),
-        (Int(a), Float(b)) => Float(a as f64 - b),
-        (Float(a), Int(b)) => Float(a - b as f64),
-        (Float(a), Float(b)) => Float(a - b),
-
-        (Decimal(a), Decimal(b)) => Decimal(a.checked_sub(b).ok_or_else(too_large)?),
-        (Decimal(a), Int(b)) => Decimal(
-            a.checked_sub(crate::foundations::Decimal::from(b))
-                .ok_or_else(too_large)?,
-        ),
-        (Int(a), Decimal(b)) => Decimal(
-            crate::foundations::Decimal::from(a)
-                .checked_sub(b)
-                .ok_or_else(too_large)?,
-        ),
+    if let Some(numbers) = promote(&lhs, &rhs) {
+        return Ok(match numbers {
+            Promoted::Int(a, b) => Int(a.checked_sub(b).ok_or_else(too_large)?),
+            Promoted::Float(a, b) => Float(a - b),
+            Promoted::Decimal(a, b) => Decimal(a.checked_sub(b).ok_or_else(too_large)?),
+        });
+    }
 
+    Ok(match (lhs, rhs) {
         (Angle(a), Angle(b)) => Angle(a - b),
 
         (Length(a), Length(b)) => Length(a - b),
@@ -218,23 +236,15 @@ pub fn sub(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
 /// Compute the product of two values.
 pub fn mul(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
     use Value::*;
-    Ok(match (lhs, rhs) {
-        (Int(a), Int(b)) => Int(a.checked_mul(b).ok_or_else(too_large)?),
-        (Int(a), Float(b)) => Float(a as f64 * b),
-        (Float(a), Int(b)) => Float(a * b as f64),
-        (Float(a), Float(b)) => Float(a * b),
-
-        (Decimal(a), Decimal(b)) => Decimal(a.checked_mul(b).ok_or_else(too_large)?),
-        (Decimal(a), Int(b)) => Decimal(
-            a.checked_mul(crate::foundations::Decimal::from(b))
-                .ok_or_else(too_large)?,
-        ),
-        (Int(a), Decimal(b)) => Decimal(
-            crate::foundations::Decimal::from(a)
-                .checked_mul(b)
-                .ok_or_else(too_large)?,
-        ),
+    if let Some(numbers) = promote(&lhs, &rhs) {
+        return Ok(match numbers {
+            Promoted::Int(a, b) => Int(a.checked_mul(b).ok_or_else(too_large)?),
+            Promoted::Float(a, b) => Float(a * b),
+            Promoted::Decimal(a, b) => Decimal(a.checked_mul(b).ok_or_else(too_large)?),
+        });
+    }
 
+    Ok(match (lhs, rhs) {
         (Length(a), Int(b)) => Length(a * b as f64),
         (Length(a), Float(b)) => Length(a * b),
         (Length(a), Ratio(b)) => Length(a * b.get()),
@@ -292,23 +302,17 @@ pub fn div(lhs: Value, rhs: Value) -> HintedStrResult<Value> {
         bail!("cannot divide by zero");
     }
 
-    Ok(match (lhs, rhs) {
-        (Int(a), Int(b)) => Float(a as f64 / b as f64),
-        (Int(a), Float(b)) => Float(a as f64 / b),
-        (Float(a), Int(b)) => Float(a / b as f64),
-        (Float(a), Float(b)) => Float(a / b),
-
-        (Decimal(a), Decimal(b)) => Decimal(a.checked_div(b).ok_or_else(too_large)?),
-        (Decimal(a), Int(b)) => Decimal(
-            a.checked_div(crate::foundations::Decimal::from(b))
-                .ok_or_else(too_large)?,
-        ),
-        (Int(a), Decimal(b)) => Decimal(
-            crate::foundations::Decimal::from(a)
-                .checked_div(b)
-                .ok_or_else(too_large)?,
-        ),
+    if let Some(numbers) = promote(&lhs, &rhs) {
+        return Ok(match numbers {
+            // Integer division always results in a float, even if it is exact,
+            // so that the result type doesn't depend on the operands' values.
+            Promoted::Int(a, b) => Float(a as f64 / b as f64),
+            Promoted::Float(a, b) => Float(a / b),
+            Promoted::Decimal(a, b) => Decimal(a.checked_div(b).ok_or_else(too_large)?),
+        });
+    }
 
+    Ok(match (lhs, rhs) {
         (Length(a), Int(b)) => Length(a / b as f64),
         (Length(a), Float(b)) => Length(a / b),
         (Length(a), Length(b)) => Float(try_div_length(a, b)?),
@@ -421,14 +425,19 @@ comparison!(geq, ">=", Ordering::Greater | Ordering::Equal);
 /// Determine whether two values are equal.
 pub fn equal(lhs: &Value, rhs: &Value) -> bool {
     use Value::*;
+    if let Some(numbers) = promote(lhs, rhs) {
+        return match numbers {
+            Promoted::Int(a, b) => a == b,
+            Promoted::Float(a, b) => a == b,
+            Promoted::Decimal(a, b) => a == b,
+        };
+    }
+
     match (lhs, rhs) {
         // Compare reflexively.
         (None, None) => true,
         (Auto, Auto) => true,
         (Bool(a), Bool(b)) => a == b,
-        (Int(a), Int(b)) => a == b,
-        (Float(a), Float(b)) => a == b,
-        (Decimal(a), Decimal(b)) => a == b,
         (Length(a), Length(b)) => a == b,
         (Angle(a), Angle(b)) => a == b,
         (Ratio(a), Ratio(b)) => a == b,
@@ -452,10 +461,6 @@ pub fn equal(lhs: &Value, rhs: &Value) -> bool {
         (Dyn(a), Dyn(b)) => a == b,
 
         // Some technically different things should compare equal.
-        (&Int(i), &Float(f)) | (&Float(f), &Int(i)) => i as f64 == f,
-        (&Int(i), &Decimal(d)) | (&Decimal(d), &Int(i)) => {
-            crate::foundations::Decimal::from(i) == d
-        }
         (&Length(len), &Relative(rel)) | (&Relative(rel), &Length(len)) => {
             len == rel.abs && rel.rel.is_zero()
         }
@@ -470,11 +475,16 @@ pub fn equal(lhs: &Value, rhs: &Value) -> bool {
 /// Compare two values.
 pub fn compare(lhs: &Value, rhs: &Value) -> StrResult<Ordering> {
     use Value::*;
+    if let Some(numbers) = promote(lhs, rhs) {
+        return Ok(match numbers {
+            Promoted::Int(a, b) => a.cmp(&b),
+            Promoted::Float(a, b) => try_cmp_values(&a, &b)?,
+            Promoted::Decimal(a, b) => a.cmp(&b),
+        });
+    }
+
     Ok(match (lhs, rhs) {
         (Bool(a), Bool(b)) => a.cmp(b),
-        (Int(a), Int(b)) => a.cmp(b),
-        (Float(a), Float(b)) => try_cmp_values(a, b)?,
-        (Decimal(a), Decimal(b)) => a.cmp(b),
         (Length(a), Length(b)) => try_cmp_values(a, b)?,
         (Angle(a), Angle(b)) => a.cmp(b),
         (Ratio(a), Ratio(b)) => a.cmp(b),
@@ -484,10 +494,6 @@ pub fn compare(lhs: &Value, rhs: &Value) -> StrResult<Ordering> {
         (Str(a), Str(b)) => a.cmp(b),
 
         // Some technically different things should be comparable.
-        (Int(a), Float(b)) => try_cmp_values(&(*a as f64), b)?,
-        (Float(a), Int(b)) => try_cmp_values(a, &(*b as f64))?,
-        (Int(a), Decimal(b)) => crate::foundations::Decimal::from(*a).cmp(b),
-        (Decimal(a), Int(b)) => a.cmp(&crate::foundations::Decimal::from(*b)),
         (Length(a), Relative(b)) if b.rel.is_zero() => try_cmp_values(a, &b.abs)?,
         (Ratio(a), Relative(b)) if b.abs.is_zero() => a.cmp(&b.rel),
         (Relative(a), Length(b)) if a.rel.is_zero() => try_cmp_values(&a.abs, b)?,
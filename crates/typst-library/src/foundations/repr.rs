@@ -1,8 +1,11 @@
 //! Debug representation of values.
 
 use ecow::{eco_format, EcoString};
+use typst_syntax::Span;
 use typst_utils::round_with_precision;
 
+use crate::diag::warning;
+use crate::engine::Engine;
 use crate::foundations::{func, Str, Value};
 
 /// The Unicode minus sign.
@@ -32,6 +35,63 @@ pub fn repr(
     value.repr().into()
 }
 
+/// Returns the string representation of a value with each item of a nested
+/// array or dictionary on its own line.
+///
+/// Other values are rendered the same as by [`repr`].
+///
+/// **Note:** This function is for debugging purposes. Its output should not be
+/// considered stable and may change at any time!
+///
+/// # Example
+/// ```example
+/// #raw(pretty((name: "Typst", tags: ("fast", "fun"))))
+/// ```
+#[func]
+pub fn pretty(
+    /// The value whose pretty string representation to produce.
+    value: Value,
+    /// The number of spaces to indent each nesting level with.
+    #[named]
+    #[default(2)]
+    indent: usize,
+) -> Str {
+    value.pretty(indent).into()
+}
+
+/// Reports the representation of a value as a warning and returns the value
+/// unchanged.
+///
+/// This lets you look at an intermediate value anywhere in an expression
+/// without affecting the result. The warning is reported at the position of
+/// the call.
+///
+/// **Note:** This function is for debugging purposes. Remove calls to it once
+/// you are done, as they will clutter the diagnostics.
+///
+/// # Example
+/// ```typ
+/// #let total = (1, 2, 3).map(x => inspect(x * 2, label: "doubled")).sum()
+/// ```
+#[func]
+pub fn inspect(
+    engine: &mut Engine,
+    span: Span,
+    /// The value to report and return.
+    value: Value,
+    /// A label to prefix the reported representation with.
+    #[named]
+    label: Option<Str>,
+) -> Value {
+    let repr = value.repr();
+    let message = match label {
+        Some(label) => eco_format!("{label}: {repr}"),
+        None => eco_format!("inspected value: {repr}"),
+    };
+    engine.sink.warn(warning!(span, "{}", message));
+    value
+}
+
 /// A trait that defines the `repr` of a Typst value.
 pub trait Repr {
     /// Return the debug representation of the value.
@@ -0,0 +1,145 @@
+use ecow::{eco_format, EcoString};
+
+use crate::diag::{bail, StrResult};
+use crate::foundations::{array, func, scope, ty, Array, Repr};
+
+/// A deterministic pseudo-random number generator.
+///
+/// As all functions in Typst are pure, there is no global source of
+/// randomness. Instead, you create a generator from a seed and pass it along
+/// explicitly. Each of its methods returns an array consisting of the
+/// generator's next state and the generated value. Creating a generator with
+/// the same seed always yields the same sequence, so your document looks the
+/// same every time it is compiled. To reseed, simply create a new generator.
+///
+/// The generator is meant for things like placeholder content or randomized
+/// examples. It is not suitable for cryptographic purposes.
+///
+/// # Example
+/// ```example
+/// #let gen = rng(42)
+/// #let (gen, x) = gen.random()
+/// #let (gen, die) = gen.random-int(1, 7)
+/// #let (gen, order) = gen.shuffle(range(5))
+///
+/// #calc.round(x, digits: 3) \
+/// #die \
+/// #order
+/// ```
+#[ty(scope, title = "Random Number Generator")]
+#[derive(Debug, Copy, Clone, PartialEq, Hash)]
+pub struct Rng(u64);
+
+impl Rng {
+    /// Create a new generator with the given seed.
+    pub fn new(seed: u64) -> Self {
+        Self(seed)
+    }
+
+    /// Advance the generator and produce the next 64 random bits.
+    ///
+    /// This is the SplitMix64 algorithm, which is tiny, fast, and has good
+    /// statistical properties for non-cryptographic use.
+    fn next_u64(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(0x9E37_79B9_7F4A_7C15);
+        let mut z = self.0;
+        z = (z ^ (z >> 30)).wrapping_mul(0xBF58_476D_1CE4_E5B9);
+        z = (z ^ (z >> 27)).wrapping_mul(0x94D0_49BB_1331_11EB);
+        z ^ (z >> 31)
+    }
+
+    /// Produce a uniformly distributed float in `[0, 1)`.
+    fn next_f64(&mut self) -> f64 {
+        // Use the top 53 bits, which is exactly the mantissa precision.
+        (self.next_u64() >> 11) as f64 / (1u64 << 53) as f64
+    }
+
+    /// Produce a uniformly distributed integer in `[0, bound)`.
+    ///
+    /// The bound must be non-zero.
+    fn next_below(&mut self, bound: u64) -> u64 {
+        // Reject values from the incomplete last bucket to avoid modulo bias.
+        let limit = u64::MAX - u64::MAX % bound;
+        loop {
+            let x = self.next_u64();
+            if x < limit {
+                return x % bound;
+            }
+        }
+    }
+}
+
+#[scope]
+impl Rng {
+    /// Creates a new random number generator from a seed.
+    ///
+    /// ```example
+    /// #let (_, a) = rng(7).random()
+    /// #let (_, b) = rng(7).random()
+    /// #(a == b)
+    /// ```
+    #[func(constructor)]
+    pub fn construct(
+        /// The seed for the generator. Generators with the same seed produce
+        /// the same sequence of values.
+        seed: i64,
+    ) -> Rng {
+        Rng::new(seed as u64)
+    }
+
+    /// Generates a random float between `{0.0}` (inclusive) and `{1.0}`
+    /// (exclusive).
+    ///
+    /// Returns an array with the advanced generator and the generated float.
+    #[func]
+    pub fn random(mut self) -> Array {
+        let value = self.next_f64();
+        array![self, value]
+    }
+
+    /// Generates a random integer in the half-open range from `low`
+    /// (inclusive) to `high` (exclusive), like [`range`].
+    ///
+    /// Returns an array with the advanced generator and the generated integer.
+    /// Fails with an error if the range is empty.
+    #[func]
+    pub fn random_int(
+        mut self,
+        /// The lower bound (inclusive).
+        low: i64,
+        /// The upper bound (exclusive).
+        high: i64,
+    ) -> StrResult<Array> {
+        if low >= high {
+            bail!("range is empty (low: {low}, high: {high})");
+        }
+        let offset = self.next_below(high.abs_diff(low));
+        let value = low.wrapping_add(offset as i64);
+        Ok(array![self, value])
+    }
+
+    /// Randomly shuffles the items of an array.
+    ///
+    /// Returns an array with the advanced generator and the shuffled array.
+    /// The original array is left untouched.
+    #[func]
+    pub fn shuffle(
+        mut self,
+        /// The array to shuffle.
+        array: Array,
+    ) -> Array {
+        // Fisher-Yates shuffle.
+        let mut items: Vec<_> = array.into_iter().collect();
+        for i in (1..items.len()).rev() {
+            let j = self.next_below(i as u64 + 1) as usize;
+            items.swap(i, j);
+        }
+        array![self, Array::from_iter(items)]
+    }
+}
+
+impl Repr for Rng {
+    fn repr(&self) -> EcoString {
+        eco_format!("rng({})", self.0 as i64)
+    }
+}
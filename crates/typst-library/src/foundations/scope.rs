@@ -1,15 +1,17 @@
+use std::collections::HashSet;
 use std::fmt::{self, Debug, Formatter};
 use std::hash::{Hash, Hasher};
 
 use ecow::{eco_format, EcoString};
 use indexmap::map::Entry;
 use indexmap::IndexMap;
+use serde::{de, ser, Deserialize, Deserializer, Serialize, Serializer};
 use typst_syntax::Span;
 
 use crate::diag::{bail, DeprecationSink, HintedStrResult, HintedString, StrResult};
 use crate::foundations::{
-    Element, Func, IntoValue, NativeElement, NativeFunc, NativeFuncData, NativeType,
-    Type, Value,
+    ops, Element, Func, IntoValue, NativeElement, NativeFunc, NativeFuncData, NativeType,
+    ParamInfo, Type, Value,
 };
 use crate::{Category, Library};
 
@@ -42,6 +44,21 @@ impl<'a> Scopes<'a> {
         self.top = self.scopes.pop().expect("no pushed scope");
     }
 
+    /// Save the state of the whole scope stack.
+    ///
+    /// This is cheap since bound values are reference-counted and not deeply
+    /// copied.
+    pub fn snapshot(&self) -> ScopesSnapshot {
+        ScopesSnapshot { top: self.top.clone(), scopes: self.scopes.clone() }
+    }
+
+    /// Restore a state saved with [`snapshot`](Self::snapshot), discarding
+    /// all scopes entered and bindings made since.
+    pub fn restore(&mut self, snapshot: ScopesSnapshot) {
+        self.top = snapshot.top;
+        self.scopes = snapshot.scopes;
+    }
+
     /// Try to access a binding immutably.
     pub fn get(&self, var: &str) -> HintedStrResult<&Binding> {
         std::iter::once(&self.top)
@@ -91,6 +108,97 @@ impl<'a> Scopes<'a> {
             })
     }
 
+    /// Resolve a dotted path like `calc.pi`.
+    ///
+    /// The first segment is resolved like a variable and each following
+    /// segment accesses a definition in a module, function, or type, or a key
+    /// in a dictionary.
+    pub fn get_in(&self, path: &[&str]) -> HintedStrResult<Value> {
+        let Some((first, rest)) = path.split_first() else {
+            bail!("cannot resolve an empty path");
+        };
+
+        let mut value = self.get(first)?.read().clone();
+        for (i, &segment) in rest.iter().enumerate() {
+            if !matches!(
+                value,
+                Value::Module(_) | Value::Func(_) | Value::Type(_) | Value::Dict(_)
+            ) {
+                bail!(
+                    "`{}` has no member `{segment}`", path[..=i].join(".");
+                    hint: "only modules, functions, types, and dictionaries have members"
+                );
+            }
+            value = value.field(segment, ())?;
+        }
+
+        Ok(value)
+    }
+
+    /// Iterate over all visible bindings, yielding each name exactly once with
+    /// the binding that [`get`](Self::get) resolves it to.
+    ///
+    /// The bindings of the active scope come first, followed by those of the
+    /// lower scopes from innermost to outermost and those of the standard
+    /// library.
+    pub fn effective(&self) -> impl Iterator<Item = (&str, &Binding)> {
+        let base = self.base.map(|base| base.global.scope());
+        let std = self.base.map(|base| ("std", &base.std));
+        let mut seen = HashSet::new();
+        std::iter::once(&self.top)
+            .chain(self.scopes.iter().rev())
+            .chain(base)
+            .flat_map(|scope| {
+                scope.iter().map(|(name, binding)| (name.as_str(), binding))
+            })
+            .chain(std)
+            .filter(move |&(name, _)| seen.insert(name))
+    }
+
+    /// Find all bindings whose name starts with `prefix`, for autocompletion.
+    ///
+    /// Candidates are ranked by scope proximity first (the active scope, then
+    /// the lower scopes from innermost to outermost, and the standard library
+    /// last) and by match quality second (case-sensitive prefix matches before
+    /// case-insensitive ones). Shadowed bindings are not included.
+    pub fn completions(&self, prefix: &str) -> Vec<Completion> {
+        let lowercase = prefix.to_lowercase();
+        let quality = |name: &str| {
+            if name.starts_with(prefix) {
+                Some(0)
+            } else if name.to_lowercase().starts_with(&lowercase) {
+                Some(1)
+            } else {
+                None
+            }
+        };
+
+        let base = self.base.map(|base| base.global.scope());
+        let scopes = std::iter::once(&self.top)
+            .chain(self.scopes.iter().rev())
+            .chain(base)
+            .enumerate();
+
+        let mut seen = HashSet::new();
+        let mut completions = vec![];
+        for (depth, scope) in scopes {
+            for (name, binding) in scope.iter() {
+                let Some(quality) = quality(name) else { continue };
+                if !seen.insert(name) {
+                    continue;
+                }
+                let kind = binding.kind();
+                completions
+                    .push((quality, Completion { name: name.clone(), kind, depth }));
+            }
+        }
+
+        completions.sort_by(|(qa, a), (qb, b)| {
+            (a.depth, qa, &a.name).cmp(&(b.depth, qb, &b.name))
+        });
+        completions.into_iter().map(|(_, completion)| completion).collect()
+    }
+
     /// Check if an std variable is shadowed.
     pub fn check_std_shadowed(&self, var: &str) -> bool {
         self.base.is_some_and(|base| base.global.scope().get(var).is_some())
@@ -100,6 +208,13 @@ impl<'a> Scopes<'a> {
     }
 }
 
+/// A saved state of a scope stack, see [`Scopes::snapshot`].
+#[derive(Debug, Clone)]
+pub struct ScopesSnapshot {
+    top: Scope,
+    scopes: Vec<Scope>,
+}
+
 /// A map from binding names to values.
 #[derive(Default, Clone)]
 pub struct Scope {
@@ -120,6 +235,22 @@ impl Scope {
         Self { deduplicate: true, ..Default::default() }
     }
 
+    /// Create a new empty scope with room for at least `capacity` bindings.
+    pub fn with_capacity(capacity: usize) -> Self {
+        Self {
+            map: IndexMap::with_capacity(capacity),
+            ..Default::default()
+        }
+    }
+
+    /// Reserve room for at least `additional` more bindings.
+    ///
+    /// Useful before defining many bindings at once to avoid repeated
+    /// reallocations.
+    pub fn reserve(&mut self, additional: usize) {
+        self.map.reserve(additional);
+    }
+
     /// Enter a new category.
     pub fn start_category(&mut self, category: Category) {
         self.category = Some(category);
@@ -181,6 +312,24 @@ impl Scope {
         binding.category = self.category;
         self.bind(name.into(), binding)
     }
+
+    /// Define many built-ins with compile-time known names at once.
+    ///
+    /// Room for all entries is reserved upfront. Like with
+    /// [`define`](Self::define), a later entry with the same name as an
+    /// earlier one overwrites it (or panics in debug builds if this scope is
+    /// deduplicating).
+    #[track_caller]
+    pub fn define_consts(
+        &mut self,
+        entries: impl IntoIterator<Item = (&'static str, Value)>,
+    ) {
+        let entries = entries.into_iter();
+        self.reserve(entries.size_hint().0);
+        for (name, value) in entries {
+            self.define(name, value);
+        }
+    }
 }
 
 /// Scope manipulation and access.
@@ -198,6 +347,24 @@ impl Scope {
         }
     }
 
+    /// Renames a binding, keeping its position in the scope and all of its
+    /// metadata (kind, span, category, and deprecation).
+    ///
+    /// Returns whether a binding named `from` existed. If there already is a
+    /// binding named `to`, it is overwritten.
+    pub fn rename(&mut self, from: &str, to: EcoString) -> bool {
+        let Some((mut index, _, binding)) = self.map.shift_remove_full(from) else {
+            return false;
+        };
+        if let Some((prev, _, _)) = self.map.shift_remove_full(&to) {
+            if prev < index {
+                index -= 1;
+            }
+        }
+        self.map.shift_insert(index, to, binding);
+        true
+    }
+
     /// Try to access a binding immutably.
     pub fn get(&self, var: &str) -> Option<&Binding> {
         self.map.get(var)
@@ -212,6 +379,58 @@ impl Scope {
     pub fn iter(&self) -> impl Iterator<Item = (&EcoString, &Binding)> {
         self.map.iter()
     }
+
+    /// Determines which bindings differ between a previous version of this
+    /// scope and this one.
+    ///
+    /// Values are compared with value equality, so a binding that was
+    /// reassigned to an equal value is not reported. Added and changed
+    /// bindings are listed in the order of this scope, followed by removed
+    /// bindings in the order of the previous scope.
+    pub fn diff(&self, previous: &Scope) -> Vec<ChangedBinding> {
+        let mut changes = vec![];
+        for (name, binding) in &self.map {
+            let change = match previous.map.get(name) {
+                None => BindingChange::Added,
+                Some(prev) if !ops::equal(prev.read(), binding.read()) => {
+                    BindingChange::Changed
+                }
+                Some(_) => continue,
+            };
+            changes.push(ChangedBinding { name: name.clone(), change });
+        }
+        for name in previous.map.keys() {
+            if !self.map.contains_key(name) {
+                changes.push(ChangedBinding {
+                    name: name.clone(),
+                    change: BindingChange::Removed,
+                });
+            }
+        }
+        changes
+    }
+
+    /// Describes all bindings in this scope in definition order, for example
+    /// to generate reference documentation.
+    ///
+    /// Parameter information is only available for built-in functions and for
+    /// the constructors of types.
+    pub fn describe(&self) -> Vec<BindingDoc> {
+        self.map
+            .iter()
+            .map(|(name, binding)| BindingDoc {
+                name: name.clone(),
+                kind: binding.kind(),
+                category: binding.category,
+                deprecation: binding.deprecation,
+                params: match binding.read() {
+                    Value::Func(func) => func.params(),
+                    Value::Type(ty) => ty.constructor().ok().and_then(|c| c.params()),
+                    _ => None,
+                },
+            })
+            .collect()
+    }
 }
 
 impl Debug for Scope {
@@ -234,6 +453,73 @@ impl Hash for Scope {
     }
 }
 
+/// Serializes the scope's bindings with their names, values, and categories.
+///
+/// Only data bindings can be serialized, that is, bindings whose values are
+/// none, booleans, numbers, strings, arrays, or dictionaries. Functions, types,
+/// modules, and all other values as well as deprecated bindings make
+/// serialization fail. When deserializing, the bindings are normal, mutable
+/// bindings without spans.
+impl Serialize for Scope {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let mut bindings = Vec::with_capacity(self.map.len());
+        for (name, binding) in &self.map {
+            if binding.deprecation.is_some() {
+                return Err(ser::Error::custom(eco_format!(
+                    "cannot serialize deprecated binding `{name}`"
+                )));
+            }
+            super::func::check_serializable(&binding.value).map_err(|err| {
+                ser::Error::custom(eco_format!("binding `{name}`: {err}"))
+            })?;
+            bindings.push(SerializedBinding {
+                name: name.clone(),
+                value: binding.value.clone(),
+                category: binding.category,
+            });
+        }
+        SerializedScope { deduplicate: self.deduplicate, bindings }.serialize(serializer)
+    }
+}
+
+impl<'de> Deserialize<'de> for Scope {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        let data = SerializedScope::deserialize(deserializer)?;
+        let mut scope = Self::with_capacity(data.bindings.len());
+        scope.deduplicate = data.deduplicate;
+        for SerializedBinding { name, value, category } in data.bindings {
+            if scope.map.contains_key(&name) {
+                return Err(de::Error::custom(eco_format!("duplicate binding `{name}`")));
+            }
+            let mut binding = Binding::detached(value);
+            binding.category = category;
+            scope.bind(name, binding);
+        }
+        Ok(scope)
+    }
+}
+
+/// The serialized form of a [`Scope`].
+#[derive(Serialize, Deserialize)]
+struct SerializedScope {
+    deduplicate: bool,
+    bindings: Vec<SerializedBinding>,
+}
+
+/// The serialized form of a [`Binding`] in a [`Scope`].
+#[derive(Serialize, Deserialize)]
+struct SerializedBinding {
+    name: EcoString,
+    value: Value,
+    category: Option<Category>,
+}
+
 /// Defines the associated scope of a Rust type.
 pub trait NativeScope {
     /// The constructor function for the type, if any.
@@ -243,13 +529,72 @@ pub trait NativeScope {
     fn scope() -> Scope;
 }
 
+/// An autocompletion candidate, see [`Scopes::completions`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Completion {
+    /// The name of the binding.
+    pub name: EcoString,
+    /// What kind of value is bound.
+    pub kind: BindingKind,
+    /// How many scopes away from the active scope the binding is defined. The
+    /// standard library is the deepest scope.
+    pub depth: usize,
+}
+
+/// What kind of value a binding holds.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BindingKind {
+    /// A function.
+    Func,
+    /// A type.
+    Type,
+    /// Any other value.
+    Value,
+}
+
+/// A description of a binding, see [`Scope::describe`].
+#[derive(Debug, Clone)]
+pub struct BindingDoc {
+    /// The name of the binding.
+    pub name: EcoString,
+    /// What kind of value is bound.
+    pub kind: BindingKind,
+    /// The category of the binding.
+    pub category: Option<Category>,
+    /// A deprecation message for the binding.
+    pub deprecation: Option<&'static str>,
+    /// The parameters, if the binding is a built-in function or a type with a
+    /// constructor.
+    pub params: Option<&'static [ParamInfo]>,
+}
+
+/// A binding that differs between two scopes, see [`Scope::diff`].
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct ChangedBinding {
+    /// The name of the binding.
+    pub name: EcoString,
+    /// How the binding differs.
+    pub change: BindingChange,
+}
+
+/// How a [`ChangedBinding`] differs from its previous version.
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+pub enum BindingChange {
+    /// The binding did not exist previously.
+    Added,
+    /// The binding's value is not equal to its previous value.
+    Changed,
+    /// The binding does not exist anymore.
+    Removed,
+}
+
 /// A bound value with metadata.
 #[derive(Debug, Clone, Hash)]
 pub struct Binding {
     /// The bound value.
     value: Value,
-    /// The kind of binding, determines how the value can be accessed.
-    kind: BindingKind,
+    /// The mode of the binding, determines how the value can be accessed.
+    mode: BindingMode,
     /// A span associated with the binding.
     span: Span,
     /// The category of the binding.
@@ -260,7 +605,7 @@ pub struct Binding {
 
 /// The different kinds of slots.
 #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-enum BindingKind {
+enum BindingMode {
     /// A normal, mutable binding.
     Normal,
     /// A captured copy of another variable.
@@ -273,7 +618,7 @@ impl Binding {
         Self {
             value: value.into_value(),
             span,
-            kind: BindingKind::Normal,
+            mode: BindingMode::Normal,
             category: None,
             deprecation: None,
         }
@@ -290,6 +635,15 @@ impl Binding {
         self
     }
 
+    /// What kind of value is bound.
+    pub fn kind(&self) -> BindingKind {
+        match self.value {
+            Value::Func(_) => BindingKind::Func,
+            Value::Type(_) => BindingKind::Type,
+            _ => BindingKind::Value,
+        }
+    }
+
     /// Read the value.
     pub fn read(&self) -> &Value {
         &self.value
@@ -311,9 +665,9 @@ impl Binding {
     ///
     /// This fails if the value is a read-only closure capture.
     pub fn write(&mut self) -> StrResult<&mut Value> {
-        match self.kind {
-            BindingKind::Normal => Ok(&mut self.value),
-            BindingKind::Captured(capturer) => bail!(
+        match self.mode {
+            BindingMode::Normal => Ok(&mut self.value),
+            BindingMode::Captured(capturer) => bail!(
                 "variables from outside the {} are \
                  read-only and cannot be modified",
                 match capturer {
@@ -327,7 +681,7 @@ impl Binding {
     /// Create a copy of the binding for closure capturing.
     pub fn capture(&self, capturer: Capturer) -> Self {
         Self {
-            kind: BindingKind::Captured(capturer),
+            mode: BindingMode::Captured(capturer),
             ..self.clone()
         }
     }
@@ -410,3 +764,312 @@ fn unknown_variable_math(var: &str, in_global: bool) -> HintedString {
 
     res
 }
+
+#[cfg(test)]
+mod tests {
+    use std::f64::consts::PI;
+
+    use super::*;
+    use crate::foundations::{Array, Dict, Str};
+
+    fn names(scope: &Scope) -> Vec<&str> {
+        scope.iter().map(|(name, _)| name.as_str()).collect()
+    }
+
+    #[test]
+    fn test_scopes_snapshot() {
+        let mut scopes = Scopes::new(None);
+        let initial = scopes.snapshot();
+
+        // Three cells, each evaluated against the state after the previous.
+        scopes.top.define("a", 1);
+        let first = scopes.snapshot();
+        scopes.top.define("b", 2);
+        scopes.enter();
+        scopes.top.define("nested", 3);
+        scopes.top.define("c", 4);
+
+        // Delete the middle cell and re-run the last one.
+        scopes.restore(first.clone());
+        scopes.top.define("c", 4);
+        assert!(scopes.get("a").is_ok());
+        assert!(scopes.get("b").is_err());
+        assert!(scopes.get("nested").is_err());
+        assert!(scopes.get("c").is_ok());
+        assert!(scopes.scopes.is_empty());
+
+        // Snapshots stay valid after restoring.
+        scopes.restore(first);
+        assert!(scopes.get("c").is_err());
+        scopes.restore(initial);
+        assert!(scopes.get("a").is_err());
+    }
+
+    #[test]
+    fn test_scopes_get_in() {
+        let library = Library::default();
+        let mut scopes = Scopes::new(Some(&library));
+        scopes.top.define("x", 1);
+        let mut inner = Dict::new();
+        inner.insert("b".into(), Value::Int(2));
+        let mut outer = Dict::new();
+        outer.insert("a".into(), inner.into_value());
+        scopes.top.define("d", outer);
+
+        assert_eq!(scopes.get_in(&["calc", "pi"]).unwrap(), Value::Float(PI));
+        assert_eq!(scopes.get_in(&["d", "a", "b"]).unwrap(), Value::Int(2));
+        assert_eq!(scopes.get_in(&["x"]).unwrap(), Value::Int(1));
+
+        // A missing final member.
+        let err = scopes.get_in(&["calc", "tau", "nope"]).unwrap_err();
+        assert_eq!(err.message(), "`calc.tau` has no member `nope`");
+        let err = scopes.get_in(&["calc", "nope"]).unwrap_err();
+        assert_eq!(err.message(), "module `calc` does not contain `nope`");
+
+        // A non-namespace intermediate segment.
+        let err = scopes.get_in(&["x", "y", "z"]).unwrap_err();
+        assert_eq!(err.message(), "`x` has no member `y`");
+        assert!(scopes.get_in(&["nope", "y"]).is_err());
+        assert!(scopes.get_in(&[]).is_err());
+    }
+
+    #[test]
+    fn test_scopes_effective() {
+        let library = Library::default();
+        let mut scopes = Scopes::new(None);
+        scopes.top.define("x", 1);
+        scopes.top.define("y", 2);
+        scopes.enter();
+        scopes.top.define("x", 3);
+        scopes.enter();
+        scopes.top.define("z", 4);
+
+        let effective: Vec<_> = scopes
+            .effective()
+            .map(|(name, binding)| (name, binding.read()))
+            .collect();
+        assert_eq!(
+            effective,
+            [("z", &Value::Int(4)), ("x", &Value::Int(3)), ("y", &Value::Int(2))]
+        );
+        assert_eq!(scopes.get("x").unwrap().read(), &Value::Int(3));
+
+        // The standard library comes last and can be shadowed, too.
+        scopes.base = Some(&library);
+        scopes.top.define("rect", 5);
+        let rects: Vec<_> = scopes
+            .effective()
+            .filter(|&(name, _)| name == "rect")
+            .map(|(_, binding)| binding.read())
+            .collect();
+        assert_eq!(rects, [&Value::Int(5)]);
+        assert_eq!(scopes.effective().last().unwrap().0, "std");
+    }
+
+    #[test]
+    fn test_scopes_completions() {
+        let library = Library::default();
+        let mut scopes = Scopes::new(Some(&library));
+        scopes.top.define("rectangles", 1);
+        scopes.enter();
+        scopes.top.define("rect", 2);
+        scopes.top.define("Recall", 3);
+
+        let completions = scopes.completions("rec");
+        let names: Vec<_> = completions
+            .iter()
+            .map(|completion| (completion.name.as_str(), completion.depth))
+            .collect();
+
+        // The local `rect` shadows the standard library's and local bindings
+        // come before global ones.
+        assert_eq!(names, [("rect", 0), ("Recall", 0), ("rectangles", 1)]);
+        assert!(completions.iter().all(|c| c.kind == BindingKind::Value));
+
+        // Without shadowing, the standard library's function is suggested.
+        scopes.exit();
+        let completions = scopes.completions("rec");
+        assert_eq!(completions[0].name, "rectangles");
+        assert_eq!(completions[1].name, "rect");
+        assert_eq!(completions[1].kind, BindingKind::Func);
+        assert_eq!(completions[1].depth, 1);
+    }
+
+    #[test]
+    fn test_scope_serialize_round_trip() {
+        let mut scope = Scope::new();
+        scope.start_category(Category::Foundations);
+        scope.define("none", Value::None);
+        scope.define("flag", true);
+        scope.define("count", 42);
+        scope.define("ratio", 0.5);
+        scope.define("name", "Typst");
+        scope.reset_category();
+        let rows: Array = (0..100)
+            .map(|i| {
+                let mut row = Dict::new();
+                row.insert("id".into(), Value::Int(i));
+                row.insert(
+                    "tags".into(),
+                    Array::from_iter(["a".into_value()]).into_value(),
+                );
+                row.into_value()
+            })
+            .collect();
+        scope.define("rows", rows);
+
+        let mut bytes = Vec::new();
+        ciborium::into_writer(&scope, &mut bytes).unwrap();
+        let restored: Scope = ciborium::from_reader(bytes.as_slice()).unwrap();
+        assert_eq!(names(&restored), names(&scope));
+        assert!(restored.diff(&scope).is_empty());
+        assert_eq!(
+            restored.get("count").unwrap().category(),
+            Some(Category::Foundations)
+        );
+        assert_eq!(restored.get("rows").unwrap().category(), None);
+
+        // Functions and deprecated bindings cannot be cached.
+        let mut scope = Scope::new();
+        scope.define_func::<crate::foundations::panic>();
+        assert!(ciborium::into_writer(&scope, Vec::new()).is_err());
+        let mut scope = Scope::new();
+        scope.define("old", 1).deprecated("`old` is deprecated");
+        assert!(ciborium::into_writer(&scope, Vec::new()).is_err());
+    }
+
+    #[test]
+    fn test_scope_with_capacity() {
+        let build = |scope: &mut Scope| {
+            for i in 0..100 {
+                scope.bind(eco_format!("v{i}"), Binding::detached(i));
+            }
+        };
+
+        let mut incremental = Scope::new();
+        build(&mut incremental);
+        let mut reserved = Scope::with_capacity(100);
+        build(&mut reserved);
+        let mut deduplicating = Scope::deduplicating();
+        deduplicating.reserve(100);
+        build(&mut deduplicating);
+
+        assert_eq!(names(&reserved), names(&incremental));
+        assert!(reserved.diff(&incremental).is_empty());
+        assert_eq!(typst_utils::hash128(&reserved), typst_utils::hash128(&incremental));
+        assert!(deduplicating.diff(&incremental).is_empty());
+    }
+
+    #[test]
+    fn test_scope_describe() {
+        let mut scope = Scope::new();
+        scope.start_category(Category::Foundations);
+        scope.define_func::<crate::foundations::repr::pretty>();
+        scope.define_type::<Str>();
+        scope.reset_category();
+        scope.define("answer", 42).deprecated("`answer` is deprecated");
+
+        let docs = scope.describe();
+        let shape: Vec<_> =
+            docs.iter().map(|doc| (doc.name.as_str(), doc.kind)).collect();
+        assert_eq!(
+            shape,
+            [
+                ("pretty", BindingKind::Func),
+                ("str", BindingKind::Type),
+                ("answer", BindingKind::Value),
+            ]
+        );
+
+        let params = docs[0].params.unwrap();
+        let params: Vec<_> = params
+            .iter()
+            .map(|param| (param.name, param.positional, param.required, param.variadic))
+            .collect();
+        assert_eq!(
+            params,
+            [("value", true, true, false), ("indent", false, false, false)]
+        );
+        assert_eq!(docs[0].category, Some(Category::Foundations));
+
+        // Types are described with their constructor's parameters.
+        let params: Vec<_> = docs[1].params.unwrap().iter().map(|p| p.name).collect();
+        assert_eq!(params, ["value", "base"]);
+        assert!(docs[2].params.is_none());
+        assert_eq!(docs[2].category, None);
+        assert_eq!(docs[2].deprecation, Some("`answer` is deprecated"));
+    }
+
+    #[test]
+    fn test_scope_define_consts() {
+        let mut scope = Scope::new();
+        scope.define("first", 0);
+        scope.define_consts([
+            ("one", Value::Int(1)),
+            ("two", Value::Int(2)),
+            ("one", Value::Int(3)),
+        ]);
+
+        assert_eq!(names(&scope), ["first", "one", "two"]);
+        assert_eq!(scope.get("one").unwrap().read(), &Value::Int(3));
+        assert_eq!(scope.get("two").unwrap().read(), &Value::Int(2));
+    }
+
+    #[test]
+    fn test_scope_diff() {
+        let mut previous = Scope::new();
+        previous.define("same", 1);
+        previous.define("equal", 2);
+        previous.define("changed", 3);
+        previous.define("removed", 4);
+
+        let mut scope = Scope::new();
+        scope.define("same", 1);
+        scope.define("equal", 2.0);
+        scope.define("changed", "three");
+        scope.define("added", 5);
+
+        let changes: Vec<_> = scope
+            .diff(&previous)
+            .into_iter()
+            .map(|changed| (changed.name, changed.change))
+            .collect();
+        assert_eq!(
+            changes,
+            [
+                ("changed".into(), BindingChange::Changed),
+                ("added".into(), BindingChange::Added),
+                ("removed".into(), BindingChange::Removed),
+            ]
+        );
+        assert!(scope.diff(&scope).is_empty());
+    }
+
+    #[test]
+    fn test_scope_rename() {
+        let mut scope = Scope::new();
+        scope.define("a", 1);
+        scope.define("b", 2);
+        scope.define("c", 3);
+
+        // Keeps the position and the binding's kind.
+        scope.bind("d".into(), Binding::detached(4).capture(Capturer::Function));
+        assert!(scope.rename("d", "e".into()));
+        assert!(scope.rename("a", "x".into()));
+        assert_eq!(names(&scope), ["x", "b", "c", "e"]);
+        assert_eq!(scope.get("x").unwrap().read(), &Value::Int(1));
+        assert!(scope.get_mut("e").unwrap().write().is_err());
+
+        // Nonexistent source.
+        assert!(!scope.rename("a", "y".into()));
+        assert_eq!(names(&scope), ["x", "b", "c", "e"]);
+
+        // Overwrites an existing target, before and after the source.
+        assert!(scope.rename("c", "x".into()));
+        assert_eq!(names(&scope), ["b", "x", "e"]);
+        assert_eq!(scope.get("x").unwrap().read(), &Value::Int(3));
+        assert!(scope.rename("b", "e".into()));
+        assert_eq!(names(&scope), ["e", "x"]);
+        assert_eq!(scope.get("e").unwrap().read(), &Value::Int(2));
+    }
+}
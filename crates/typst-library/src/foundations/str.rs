@@ -10,7 +10,7 @@ use typst_syntax::{Span, Spanned};
 use unicode_normalization::UnicodeNormalization;
 use unicode_segmentation::UnicodeSegmentation;
 
-use crate::diag::{bail, At, SourceResult, StrResult};
+use crate::diag::{bail, At, HintedStrResult, SourceResult, StrResult};
 use crate::engine::Engine;
 use crate::foundations::{
     cast, dict, func, repr, scope, ty, Array, Bytes, Cast, Context, Decimal, Dict, Func,
@@ -623,6 +623,70 @@ impl Str {
         }
     }
 
+    /// Fills the placeholders in the string with the given values.
+    ///
+    /// Each `{}` is replaced with the next value and each `{n}` with the value
+    /// at index `n`. Strings and symbols are inserted as-is and all other
+    /// values in their [representation]($repr). To insert a literal brace,
+    /// double it: `{{` or `}}`.
+    ///
+    /// ```example
+    /// #"{} + {} = {}".format(1, 2, 1 + 2) \
+    /// #"{1}, {0}!".format("World", "Hello") \
+    /// #"{{literal}}".format()
+    /// ```
+    #[func]
+    pub fn format(
+        &self,
+        /// The values to insert.
+        #[variadic]
+        values: Vec<Value>,
+    ) -> HintedStrResult<Str> {
+        let mut out = EcoString::with_capacity(self.0.len());
+        let mut next = 0;
+        let mut chars = self.as_str().chars().peekable();
+        while let Some(c) = chars.next() {
+            match c {
+                '{' if chars.next_if_eq(&'{').is_some() => out.push('{'),
+                '}' if chars.next_if_eq(&'}').is_some() => out.push('}'),
+                '{' => {
+                    let mut digits = EcoString::new();
+                    loop {
+                        match chars.next() {
+                            Some('}') => break,
+                            Some(c) if c.is_ascii_digit() => digits.push(c),
+                            _ => bail!(
+                                "invalid placeholder in format string";
+                                hint: "placeholders are written as `{{}}` or `{{0}}`";
+                                hint: "use `{{{{` to insert a literal brace"
+                            ),
+                        }
+                    }
+
+                    let index = if digits.is_empty() {
+                        next += 1;
+                        next - 1
+                    } else {
+                        digits.parse().map_err(|_| "placeholder index is too large")?
+                    };
+
+                    match values.get(index) {
+                        Some(Value::Str(v)) => out.push_str(v),
+                        Some(Value::Symbol(v)) => out.push(v.get()),
+                        Some(v) => out.push_str(&v.repr()),
+                        None => bail!("missing value for placeholder {index}"),
+                    }
+                }
+                '}' => bail!(
+                    "unmatched closing brace in format string";
+                    hint: "use `}}}}` to insert a literal brace"
+                ),
+                c => out.push(c),
+            }
+        }
+        Ok(out.into())
+    }
+
     /// Reverse the string.
     #[func(title = "Reverse")]
     pub fn rev(&self) -> Str {
@@ -793,6 +857,42 @@ cast! {
     v: Str => v.into(),
 }
 
+/// An identifier-like name, for functions that take "a name" and shouldn't
+/// care whether it was given as a string, a label, or a symbol.
+///
+/// Deliberately separate from the cast for [`EcoString`] so that plain string
+/// parameters don't start accepting labels.
+#[derive(Debug, Clone, Eq, PartialEq, Hash)]
+pub struct Name(EcoString);
+
+impl Name {
+    /// Extract the underlying string.
+    pub fn into_inner(self) -> EcoString {
+        self.0
+    }
+}
+
+impl Deref for Name {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        &self.0
+    }
+}
+
+impl From<Name> for EcoString {
+    fn from(name: Name) -> Self {
+        name.0
+    }
+}
+
+cast! {
+    Name,
+    self => Value::Str(self.0.into()),
+    v: Str => Self(v.into()),
+    v: Label => Self(v.resolve().as_str().into()),
+}
+
 /// A value that can be cast to a string.
 pub enum ToStr {
     /// A string value ready to be used as-is.
@@ -1012,3 +1112,26 @@ cast! {
     v: Str => Self::Str(v),
     v: Func => Self::Func(v)
 }
+
+#[cfg(test)]
+mod tests {
+    use typst_utils::PicoStr;
+
+    use super::*;
+    use crate::foundations::{FromValue, Symbol};
+
+    #[test]
+    fn test_name_cast() {
+        let cast = |value: Value| Name::from_value(value).map(Name::into_inner);
+        assert_eq!(cast(Value::Str("key".into())).unwrap(), "key");
+        assert_eq!(
+            cast(Value::Label(Label::new(PicoStr::intern("key")))).unwrap(),
+            "key"
+        );
+        assert_eq!(cast(Value::Symbol(Symbol::single('α'))).unwrap(), "α");
+        assert_eq!(
+            cast(Value::Int(1)).unwrap_err().message(),
+            "expected string or label, found integer"
+        );
+    }
+}
@@ -1,18 +1,129 @@
 //! System-related things.
 
-use crate::foundations::{Dict, Module, Scope, Version};
+use typst_syntax::Span;
+
+use crate::diag::{bail, StrResult};
+use crate::engine::Engine;
+use crate::foundations::{
+    func, Array, Dict, IntoValue, Module, NoneValue, Scope, Str, Value, Version,
+};
+use crate::Features;
 
 /// A module with system-related things.
-pub fn module(inputs: Dict) -> Module {
+pub fn module(inputs: Dict, features: &Features) -> Module {
     let mut scope = Scope::deduplicating();
+    scope.define("version", version());
+    scope.define("inputs", inputs);
     scope.define(
-        "version",
-        Version::from_iter([
-            env!("CARGO_PKG_VERSION_MAJOR").parse::<u32>().unwrap(),
-            env!("CARGO_PKG_VERSION_MINOR").parse::<u32>().unwrap(),
-            env!("CARGO_PKG_VERSION_PATCH").parse::<u32>().unwrap(),
-        ]),
+        "features",
+        features
+            .iter()
+            .map(|feature| feature.name().into_value())
+            .collect::<Array>(),
     );
-    scope.define("inputs", inputs);
+    scope.define_func::<require_version>();
+    scope.define_func::<supports>();
+    scope.define_func::<position>();
     Module::new("sys", scope)
 }
+
+/// The version of the running compiler.
+fn version() -> Version {
+    Version::from_iter([
+        env!("CARGO_PKG_VERSION_MAJOR").parse::<u32>().unwrap(),
+        env!("CARGO_PKG_VERSION_MINOR").parse::<u32>().unwrap(),
+        env!("CARGO_PKG_VERSION_PATCH").parse::<u32>().unwrap(),
+    ])
+}
+
+/// Ensures that the running compiler is at least the given version.
+///
+/// Fails with an error if the compiler is older than `min`. This lets packages
+/// and templates fail early with a clear message instead of running into a
+/// missing function or parameter later on. Does not produce any output in the
+/// document.
+///
+/// ```typ
+/// #sys.require-version(version(0, 12))
+/// ```
+#[func]
+pub fn require_version(
+    /// The minimum compiler version that is required.
+    min: Version,
+) -> StrResult<NoneValue> {
+    let current = version();
+    if current < min {
+        bail!(
+            "this document requires Typst {min} or newer, \
+             but the running version is {current}"
+        );
+    }
+    Ok(NoneValue)
+}
+
+/// Whether an in-development feature is enabled in the running compiler.
+///
+/// The names of all enabled features are also available as the array
+/// `sys.features`. This lets packages and templates use a feature when it is
+/// available and degrade gracefully otherwise. Returns `{false}` for unknown
+/// feature names.
+///
+/// ```typ
+/// #if sys.supports("html") [
+///   HTML export is available.
+/// ]
+/// ```
+#[func]
+pub fn supports(
+    engine: &mut Engine,
+    /// The name of the feature, for example `{"html"}`.
+    name: Str,
+) -> bool {
+    engine
+        .world
+        .library()
+        .features
+        .iter()
+        .any(|feature| feature.name() == name.as_str())
+}
+
+/// The position in the source code at which this function is called.
+///
+/// Returns a dictionary with the `path` of the file (relative to the project
+/// root) and the 1-based `line` and `column` of the call. The entries are
+/// `{none}` if the position is unknown. This is useful for debugging output and
+/// for packages that want to report where they were used.
+///
+/// Note that this reports the position of the `sys.position` call itself, so
+/// calling it inside a function always yields a position within that
+/// function's definition.
+///
+/// ```example
+/// #let pos = sys.position()
+/// Line #pos.line, column #pos.column
+/// ```
+#[func]
+pub fn position(engine: &mut Engine, span: Span) -> Dict {
+    let mut path = Value::None;
+    let mut line = Value::None;
+    let mut column = Value::None;
+    if let Some(source) = span.id().and_then(|id| engine.world.source(id).ok()) {
+        path = source
+            .id()
+            .vpath()
+            .as_rootless_path()
+            .to_string_lossy()
+            .as_ref()
+            .into_value();
+        if let Some(start) = source.range(span).map(|range| range.start) {
+            line = source.byte_to_line(start).map(|l| l + 1).into_value();
+            column = source.byte_to_column(start).map(|c| c + 1).into_value();
+        }
+    }
+
+    let mut dict = Dict::new();
+    dict.insert("path".into(), path);
+    dict.insert("line".into(), line);
+    dict.insert("column".into(), column);
+    dict
+}
@@ -1,14 +1,14 @@
 use std::any::{Any, TypeId};
 use std::cmp::Ordering;
 use std::fmt::{self, Debug, Formatter};
-use std::hash::{Hash, Hasher};
+use std::hash::{DefaultHasher, Hash, Hasher};
 use std::sync::Arc;
 
 use ecow::{eco_format, EcoString};
 use serde::de::value::{MapAccessDeserializer, SeqAccessDeserializer};
 use serde::de::{Error, MapAccess, SeqAccess, Visitor};
 use serde::{Deserialize, Deserializer, Serialize, Serializer};
-use typst_syntax::{ast, Span};
+use typst_syntax::{ast, is_ident, Span};
 use typst_utils::ArcExt;
 
 use crate::diag::{DeprecationSink, HintedStrResult, HintedString, StrResult};
@@ -206,6 +206,57 @@ impl Value {
         }
     }
 
+    /// Return the representation of the value with each item of a nested
+    /// array or dictionary on its own line, indented by `indent` spaces per
+    /// level.
+    ///
+    /// Other values, including empty arrays and dictionaries, are rendered
+    /// inline with their [`repr`](Repr::repr).
+    pub fn pretty(&self, indent: usize) -> EcoString {
+        let mut buf = EcoString::new();
+        self.write_pretty(&mut buf, indent, 0);
+        buf
+    }
+
+    /// Write the pretty representation of the value at the given nesting
+    /// level.
+    fn write_pretty(&self, buf: &mut EcoString, indent: usize, level: usize) {
+        let pad = |buf: &mut EcoString, level: usize| {
+            for _ in 0..indent * level {
+                buf.push(' ');
+            }
+        };
+        match self {
+            Self::Array(array) if !array.is_empty() => {
+                buf.push_str("(\n");
+                for value in array {
+                    pad(buf, level + 1);
+                    value.write_pretty(buf, indent, level + 1);
+                    buf.push_str(",\n");
+                }
+                pad(buf, level);
+                buf.push(')');
+            }
+            Self::Dict(dict) if !dict.is_empty() => {
+                buf.push_str("(\n");
+                for (key, value) in dict {
+                    pad(buf, level + 1);
+                    if is_ident(key) {
+                        buf.push_str(key);
+                    } else {
+                        buf.push_str(&key.repr());
+                    }
+                    buf.push_str(": ");
+                    value.write_pretty(buf, indent, level + 1);
+                    buf.push_str(",\n");
+                }
+                pad(buf, level);
+                buf.push(')');
+            }
+            _ => buf.push_str(&self.repr()),
+        }
+    }
+
     /// Attach a span to the value, if possible.
     pub fn spanned(self, span: Span) -> Self {
         match self {
@@ -214,6 +265,96 @@ impl Value {
             v => v,
         }
     }
+
+    /// Whether two values are structurally equal.
+    ///
+    /// This is distinct from the semantics of the `==` operator (see
+    /// [`ops::equal`]) only for functions: Under `==`, a closure is only equal
+    /// to a closure created by the very same closure expression. Structurally,
+    /// two closures are equal if they have the same source code (ignoring where
+    /// it is located), the same default values, and the same captured
+    /// variables. Arrays, dictionaries, and arguments are compared recursively.
+    pub fn structural_eq(&self, other: &Self) -> bool {
+        match (self, other) {
+            (Self::Array(a), Self::Array(b)) => {
+                a.len() == b.len() && a.iter().zip(b).all(|(x, y)| x.structural_eq(y))
+            }
+            (Self::Dict(a), Self::Dict(b)) => {
+                a.len() == b.len()
+                    && a.iter()
+                        .all(|(key, x)| b.get(key).is_ok_and(|y| x.structural_eq(y)))
+            }
+            (Self::Args(a), Self::Args(b)) => a.structural_eq(b),
+            (Self::Func(a), Self::Func(b)) => a.structural_eq(b),
+            _ => ops::equal(self, other),
+        }
+    }
+
+    /// Feed the value into a hasher such that values which are equal under
+    /// `==` (see [`ops::equal`]) also produce the same hash. For example,
+    /// `{1}`, `{1.0}`, and `{decimal("1")}` all hash the same.
+    ///
+    /// The [`Hash`] implementation deliberately does not give this guarantee:
+    /// Hashes are used as memoization keys, so values which behave differently
+    /// (`{repr(1)}` isn't `{repr(1.0)}`) must hash differently.
+    pub fn hash_canonical<H: Hasher>(&self, state: &mut H) {
+        let number = |v: f64, state: &mut H| {
+            state.write_u8(0);
+            hash_float(v, state);
+        };
+        let relative = |rel: Rel<Length>, state: &mut H| {
+            state.write_u8(1);
+            hash_float(rel.rel.get(), state);
+            hash_float(rel.abs.abs.to_raw(), state);
+            hash_float(rel.abs.em.get(), state);
+        };
+
+        match self {
+            Self::Int(v) => number(*v as f64, state),
+            Self::Float(v) => number(*v, state),
+            Self::Decimal(v) if v.is_integer() => match i64::try_from(*v) {
+                Ok(v) => number(v as f64, state),
+                Err(_) => self.hash(state),
+            },
+            Self::Length(v) => relative((*v).into(), state),
+            Self::Ratio(v) => relative((*v).into(), state),
+            Self::Relative(v) => relative(*v, state),
+            Self::Array(array) => {
+                state.write_u8(2);
+                state.write_usize(array.len());
+                for item in array {
+                    item.hash_canonical(state);
+                }
+            }
+            Self::Dict(dict) => {
+                // Dictionaries compare equal regardless of their order, so
+                // the entries' hashes are combined commutatively.
+                state.write_u8(3);
+                state.write_usize(dict.len());
+                let mut sum = 0u64;
+                for (key, value) in dict {
+                    let mut hasher = DefaultHasher::new();
+                    key.hash(&mut hasher);
+                    value.hash_canonical(&mut hasher);
+                    sum = sum.wrapping_add(hasher.finish());
+                }
+                state.write_u64(sum);
+            }
+            Self::Args(args) => {
+                state.write_u8(4);
+                Value::Array(args.to_pos()).hash_canonical(state);
+                Value::Dict(args.to_named()).hash_canonical(state);
+            }
+            _ => self.hash(state),
+        }
+    }
+}
+
+/// Hashes a float such that `{0.0}` and `{-0.0}`, which compare equal, hash
+/// the same.
+fn hash_float<H: Hasher>(v: f64, state: &mut H) {
+    let v = if v == 0.0 { 0.0 } else { v };
+    v.to_bits().hash(state);
 }
 
 impl Debug for Value {
@@ -746,4 +887,79 @@ mod tests {
         test(dict!["one" => 1], "(one: 1)");
         test(dict!["two" => false, "one" => 1], "(two: false, one: 1)");
     }
+
+    #[test]
+    fn test_value_hash_canonical() {
+        use std::str::FromStr;
+
+        #[track_caller]
+        fn test(a: impl IntoValue, b: impl IntoValue, eq: bool) {
+            let hash = |value: &Value| {
+                let mut hasher = DefaultHasher::new();
+                value.hash_canonical(&mut hasher);
+                hasher.finish()
+            };
+            let (a, b) = (a.into_value(), b.into_value());
+            assert_eq!(a == b, eq);
+            assert_eq!(hash(&a) == hash(&b), eq);
+        }
+
+        // Numbers.
+        test(1, 1.0, true);
+        test(1, Decimal::from(1), true);
+        test(0.0, -0.0, true);
+        test(1, 2, false);
+        test(1.5, 2.5, false);
+        test(Decimal::from_str("1.5").unwrap(), 1.5, false);
+
+        // Lengths and ratios.
+        test(Abs::pt(1.0), Rel::new(Ratio::zero(), Length::from(Abs::pt(1.0))), true);
+        test(Ratio::one(), Rel::new(Ratio::one(), Length::zero()), true);
+        test(Abs::pt(1.0), Abs::pt(2.0), false);
+
+        // Collections.
+        test(array![1, 2.0], array![1.0, 2], true);
+        test(array![1, 2], array![2, 1], false);
+        test(dict!["a" => 1, "b" => 2.0], dict!["b" => 2, "a" => 1.0], true);
+        test(dict!["a" => 1], dict!["a" => 2], false);
+
+        // The regular hash distinguishes the two for memoization.
+        assert_ne!(
+            typst_utils::hash128(&Value::Int(1)),
+            typst_utils::hash128(&Value::Float(1.0))
+        );
+    }
+
+    #[test]
+    fn test_value_structural_eq() {
+        use typst_syntax::Source;
+
+        use crate::foundations::func::closure_from;
+
+        let source = Source::detached(
+            "#let f = x => x + y\n#let g = x => x + y\n#let h = x => x * y",
+        );
+        let closure = |i: usize, y: i64| {
+            let mut closure = closure_from(&source, i);
+            closure.captured.define("y", y);
+            Value::Func(closure.into())
+        };
+
+        // Same code and captures but different locations.
+        assert_ne!(closure(0, 1), closure(1, 1));
+        assert!(closure(0, 1).structural_eq(&closure(1, 1)));
+
+        // Different captures or code.
+        assert!(!closure(0, 1).structural_eq(&closure(1, 2)));
+        assert!(!closure(0, 1).structural_eq(&closure(2, 1)));
+
+        // Collections and other values.
+        let a = array![1, closure(0, 1)].into_value();
+        let b = array![1.0, closure(1, 1)].into_value();
+        assert!(a.structural_eq(&b));
+        let a = dict!["f" => closure(0, 1)].into_value();
+        let b = dict!["f" => closure(1, 1)].into_value();
+        assert!(a.structural_eq(&b));
+        assert!(!Value::Int(1).structural_eq(&Value::Int(2)));
+    }
 }
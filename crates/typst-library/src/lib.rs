@@ -218,6 +218,14 @@ impl Features {
     pub fn is_enabled(&self, feature: Feature) -> bool {
         self.0.contains(feature as usize)
     }
+
+    /// Iterate over the enabled features.
+    pub fn iter(&self) -> impl Iterator<Item = Feature> + '_ {
+        Feature::ALL
+            .iter()
+            .copied()
+            .filter(|&feature| self.is_enabled(feature))
+    }
 }
 
 impl FromIterator<Feature> for Features {
@@ -230,11 +238,33 @@ impl FromIterator<Feature> for Features {
     }
 }
 
-/// An in-development feature that should be enabled.
-#[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
-#[non_exhaustive]
-pub enum Feature {
-    Html,
+/// Defines the [`Feature`] enum together with the list of all features and
+/// their names, so that the three can't get out of sync.
+macro_rules! features {
+    ($($variant:ident => $name:literal),* $(,)?) => {
+        /// An in-development feature that should be enabled.
+        #[derive(Debug, Copy, Clone, Eq, PartialEq, Hash)]
+        #[non_exhaustive]
+        pub enum Feature {
+            $($variant),*
+        }
+
+        impl Feature {
+            /// All features.
+            const ALL: &[Self] = &[$(Self::$variant),*];
+
+            /// The name of the feature, as used in `sys.features`.
+            pub fn name(self) -> &'static str {
+                match self {
+                    $(Self::$variant => $name),*
+                }
+            }
+        }
+    };
+}
+
+features! {
+    Html => "html",
 }
 
 /// A group of related standard library definitions.
@@ -43,6 +43,18 @@ pub fn csv(
     #[named]
     #[default(RowType::Array)]
     row_type: RowType,
+    /// Whether to convert numeric fields into integers and floats.
+    ///
+    /// If enabled, fields like `42`, `-7`, or `1.5e3` are turned into numbers
+    /// while all other fields remain strings.
+    ///
+    /// ```example
+    /// #let data = csv(bytes("a,b\n1,2.5\nx,3"), numbers: true)
+    /// #data
+    /// ```
+    #[named]
+    #[default(false)]
+    numbers: bool,
 ) -> SourceResult<Array> {
     let data = source.load(engine.world)?;
 
@@ -75,14 +87,21 @@ pub fn csv(
         // https://github.com/BurntSushi/rust-csv/issues/184
         let line = line + line_offset;
         let row = result.map_err(|err| format_csv_error(err, line)).at(source.span)?;
+        let convert = |field: &str| {
+            if numbers {
+                parse_number(field)
+            } else {
+                field.into_value()
+            }
+        };
         let item = if let Some(headers) = &headers {
             let mut dict = Dict::new();
             for (field, value) in headers.iter().zip(&row) {
-                dict.insert(field.into(), value.into_value());
+                dict.insert(field.into(), convert(value));
             }
             dict.into_value()
         } else {
-            let sub = row.into_iter().map(|field| field.into_value()).collect();
+            let sub = row.into_iter().map(convert).collect();
             Value::Array(sub)
         };
         array.push(item);
@@ -116,7 +135,7 @@ impl csv {
         #[default(RowType::Array)]
         row_type: RowType,
     ) -> SourceResult<Array> {
-        csv(engine, data.map(Readable::into_source), delimiter, row_type)
+        csv(engine, data.map(Readable::into_source), delimiter, row_type, false)
     }
 }
 
@@ -163,6 +182,24 @@ cast! {
     },
 }
 
+/// Converts a numeric-looking field into an integer or float and leaves all
+/// other fields as strings.
+fn parse_number(field: &str) -> Value {
+    // Rust's float parser also accepts things like `inf` and `NaN`, which
+    // should stay strings.
+    let numeric = field.bytes().any(|b| b.is_ascii_digit())
+        && field.bytes().all(|b| b.is_ascii_digit() || b"+-.eE".contains(&b));
+    if numeric {
+        if let Ok(int) = field.parse::<i64>() {
+            return int.into_value();
+        }
+        if let Ok(float) = field.parse::<f64>() {
+            return float.into_value();
+        }
+    }
+    field.into_value()
+}
+
 /// Format the user-facing CSV error message.
 fn format_csv_error(err: ::csv::Error, line: usize) -> EcoString {
     match err.kind() {
@@ -1,9 +1,10 @@
 use ecow::{eco_format, EcoString};
+use serde::{ser, Serialize, Serializer};
 use typst_syntax::{is_newline, Spanned};
 
-use crate::diag::{At, FileError, SourceResult};
+use crate::diag::{bail, At, FileError, SourceResult, StrResult};
 use crate::engine::Engine;
-use crate::foundations::{func, scope, Str, Value};
+use crate::foundations::{func, scope, Datetime, Str, Value};
 use crate::loading::{DataSource, Load, Readable};
 
 /// Reads structured data from a TOML file.
@@ -53,6 +54,8 @@ impl toml {
     }
 
     /// Encodes structured data into a TOML string.
+    ///
+    /// Datetimes are encoded as native TOML dates, times, or datetimes.
     #[func(title = "Encode TOML")]
     pub fn encode(
         /// Value to be encoded.
@@ -63,6 +66,7 @@ impl toml {
         pretty: bool,
     ) -> SourceResult<Str> {
         let Spanned { v: value, span } = value;
+        let value = TomlValue(&value);
         if pretty { ::toml::to_string_pretty(&value) } else { ::toml::to_string(&value) }
             .map(|v| v.into())
             .map_err(|err| eco_format!("failed to encode value as TOML ({err})"))
@@ -70,6 +74,54 @@ impl toml {
     }
 }
 
+/// Serializes a value such that datetimes become native TOML datetimes.
+struct TomlValue<'a>(&'a Value);
+
+impl Serialize for TomlValue<'_> {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        match self.0 {
+            Value::Datetime(datetime) => datetime_to_toml(*datetime)
+                .map_err(ser::Error::custom)?
+                .serialize(serializer),
+            Value::Array(array) => serializer.collect_seq(array.iter().map(TomlValue)),
+            Value::Dict(dict) => {
+                serializer.collect_map(dict.iter().map(|(k, v)| (k, TomlValue(v))))
+            }
+            value => value.serialize(serializer),
+        }
+    }
+}
+
+/// Convert a datetime into a TOML datetime.
+fn datetime_to_toml(datetime: Datetime) -> StrResult<::toml::value::Datetime> {
+    let date = |date: time::Date| -> StrResult<::toml::value::Date> {
+        let year = date.year();
+        if !(0..=9999).contains(&year) {
+            bail!("year {year} is out of range, TOML supports years 0 to 9999");
+        }
+        Ok(::toml::value::Date {
+            year: year as u16,
+            month: date.month() as u8,
+            day: date.day(),
+        })
+    };
+    let time = |time: time::Time| ::toml::value::Time {
+        hour: time.hour(),
+        minute: time.minute(),
+        second: time.second(),
+        nanosecond: time.nanosecond(),
+    };
+    let (d, t) = match datetime {
+        Datetime::Date(d) => (Some(date(d)?), None),
+        Datetime::Time(t) => (None, Some(time(t))),
+        Datetime::Datetime(dt) => (Some(date(dt.date())?), Some(time(dt.time()))),
+    };
+    Ok(::toml::value::Datetime { date: d, time: t, offset: None })
+}
+
 /// Format the user-facing TOML error message.
 fn format_toml_error(error: ::toml::de::Error, raw: &str) -> EcoString {
     if let Some(head) = error.span().and_then(|range| raw.get(..range.start)) {
@@ -15,6 +15,7 @@ pub(super) fn define_math(math: &mut Scope) {
 }
 
 fn extend_scope_from_codex_module(scope: &mut Scope, module: codex::Module) {
+    scope.reserve(module.iter().count());
     for (name, binding) in module.iter() {
         let value = match binding.def {
             codex::Def::Symbol(s) => Value::Symbol(s.into()),
@@ -2018,13 +2018,27 @@ impl<'a> LetBinding<'a> {
         }
     }
 
+    /// The type annotation of the binding, if any: `int` in `let x: int = 1`.
+    pub fn annotation(self) -> Option<Expr<'a>> {
+        match self.kind() {
+            LetBindingKind::Normal(_) => self
+                .0
+                .children()
+                .skip_while(|node| node.kind() != SyntaxKind::Colon)
+                .take_while(|node| node.kind() != SyntaxKind::Eq)
+                .find_map(SyntaxNode::cast),
+            LetBindingKind::Closure(_) => None,
+        }
+    }
+
     /// The expression the binding is initialized with.
     pub fn init(self) -> Option<Expr<'a>> {
         match self.kind() {
-            LetBindingKind::Normal(Pattern::Normal(_) | Pattern::Parenthesized(_)) => {
-                self.0.children().filter_map(SyntaxNode::cast).nth(1)
-            }
-            LetBindingKind::Normal(_) => self.0.try_cast_first(),
+            LetBindingKind::Normal(_) => self
+                .0
+                .children()
+                .skip_while(|node| node.kind() != SyntaxKind::Eq)
+                .find_map(SyntaxNode::cast),
             LetBindingKind::Closure(_) => self.0.try_cast_first(),
         }
     }
@@ -834,6 +834,12 @@ fn let_binding(p: &mut Parser) {
         other = true;
     }
 
+    // An optional type annotation: `let x: int = 1`. It must not swallow the
+    // `=`, which would otherwise be parsed as an assignment.
+    if !closure && p.eat_if(SyntaxKind::Colon) {
+        code_expr_prec(p, false, ast::BinOp::Or.precedence());
+    }
+
     let f = if closure || other { Parser::expect } else { Parser::eat_if };
     if f(p, SyntaxKind::Eq) {
         code_expr(p);
@@ -1,5 +1,6 @@
 //! Performance timing for Typst.
 
+use std::collections::HashMap;
 use std::io::Write;
 use std::num::NonZeroU64;
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
@@ -138,6 +139,67 @@ pub fn export_json<W: Write>(
     Ok(())
 }
 
+/// Aggregated timing data for all recorded scopes with the same name and span.
+///
+/// For `func call` scopes, the span is the span of the called function, so
+/// there is one summary per function.
+#[derive(Debug, Clone, PartialEq)]
+pub struct Summary {
+    /// The name of the scopes (e.g. `func call`).
+    pub name: &'static str,
+    /// The raw value of the span the scopes were recorded with.
+    pub span: Option<NonZeroU64>,
+    /// How often a scope with this name and span was entered.
+    pub count: usize,
+    /// The cumulative time spent in the scopes, in microseconds.
+    ///
+    /// Time spent in nested scopes with the same name and span (e.g. in a
+    /// recursive function) is counted for each of them.
+    pub total: f64,
+}
+
+/// Summarize the recorded events by name and span, e.g. to find out how often
+/// a function was called and how much time was spent in it.
+///
+/// The summaries are sorted by descending total time. Scopes that haven't
+/// ended yet are not included.
+pub fn summarize() -> Vec<Summary> {
+    let lock = EVENTS.lock();
+
+    let mut open: Vec<&Event> = vec![];
+    let mut totals: HashMap<(&'static str, Option<NonZeroU64>), Summary> = HashMap::new();
+    for event in lock.iter() {
+        match event.kind {
+            EventKind::Start => open.push(event),
+            EventKind::End => {
+                let Some(index) = open.iter().rposition(|start| {
+                    start.thread_id == event.thread_id
+                        && start.name == event.name
+                        && start.span == event.span
+                }) else {
+                    continue;
+                };
+
+                let start = open.remove(index);
+                let elapsed = event.timestamp.micros_since(start.timestamp);
+                let summary =
+                    totals.entry((event.name, event.span)).or_insert_with(|| Summary {
+                        name: event.name,
+                        span: event.span,
+                        count: 0,
+                        total: 0.0,
+                    });
+                summary.count += 1;
+                summary.total += elapsed;
+            }
+        }
+    }
+
+    let mut summaries: Vec<Summary> = totals.into_values().collect();
+    summaries.sort_by(|a, b| b.total.total_cmp(&a.total));
+    summaries
+}
+
 /// A scope that records an event when it is dropped.
 pub struct TimingScope {
     name: &'static str,
@@ -307,3 +369,41 @@ impl WasmTimer {
         self.time_origin + self.perf.now()
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_summarize() {
+        // Disabled timing doesn't record anything.
+        assert!(TimingScope::new("disabled").is_none());
+
+        enable();
+        let span = NonZeroU64::new(1);
+        let other = NonZeroU64::new(2);
+        for _ in 0..3 {
+            let _outer = TimingScope::with_span("func call", span);
+            let _inner = TimingScope::new("parse");
+        }
+        drop(TimingScope::with_span("func call", other));
+        let _unfinished = TimingScope::new("eval");
+
+        // Calls of different functions are summarized separately.
+        let summaries = summarize();
+        assert_eq!(summaries.len(), 3);
+        let calls = |span| {
+            summaries
+                .iter()
+                .find(|s| s.name == "func call" && s.span == span)
+                .unwrap()
+        };
+        let call = calls(span);
+        assert_eq!(call.count, 3);
+        assert_eq!(calls(other).count, 1);
+        let parse = summaries.iter().find(|s| s.name == "parse").unwrap();
+        assert_eq!(parse.span, None);
+        assert_eq!(parse.count, 3);
+        assert!(call.total >= parse.total);
+    }
+}
@@ -48,9 +48,19 @@
 // Error: 6-14 expected expression, found keyed pair
 #(1, "key": 2)
 
---- array-bad-conversion-from-string ---
-// Error: 8-15 expected array, bytes, or version, found string
-#array("hello")
+--- array-conversion ---
+#test(array((1, 2)), (1, 2))
+#test(array(bytes((1, 2))), (1, 2))
+#test(array(version(1, 2)), (1, 2))
+#test(array("hey"), ("h", "e", "y"))
+#test(array("🏳️‍🌈!"), ("🏳️‍🌈", "!"))
+#test(array(""), ())
+#test(array((a: 1, b: 2)), (("a", 1), ("b", 2)))
+#test(array(range(3)), (0, 1, 2))
+
+--- array-bad-conversion ---
+// Error: 8-10 expected array, bytes, version, string, or dictionary, found integer
+#array(12)
 
 --- spread-into-array ---
 // Test spreading into array and dictionary.
@@ -202,6 +212,34 @@
   test(tasks.at("b"), (4, 5, 6, 7))
 }
 
+--- array-assign-copies ---
+// Assignment copies arrays and dictionaries, including nested ones, so
+// mutating the copy leaves the original untouched.
+#{
+  let original = (1, (2, 3), (a: 4))
+  let copy = original
+  copy.at(1).push(5)
+  copy.at(2).insert("b", 6)
+  copy.push(7)
+  test(original, (1, (2, 3), (a: 4)))
+  test(copy, (1, (2, 3, 5), (a: 4, b: 6), 7))
+}
+
+--- array-push-self ---
+// Arrays are values, so pushing an array into itself stores a copy rather
+// than creating a cycle.
+#{
+  let array = (1, 2)
+  array.push(array)
+  array.push(array)
+  test(array, (1, 2, (1, 2), (1, 2, (1, 2))))
+  test(repr(array), "(1, 2, (1, 2), (1, 2, (1, 2)))")
+
+  let dict = (a: 1)
+  dict.insert("self", dict)
+  test(repr(dict), "(a: 1, self: (a: 1))")
+}
+
 --- array-insert-and-remove ---
 // Test the `insert` and `remove` methods.
 #{
@@ -236,6 +274,29 @@
 // Error: 2-24 array index out of bounds (index: -4, len: 3)
 #(1, 2, 3).slice(0, -4)
 
+--- array-contains ---
+// Test the `contains` method.
+#test((1, "a", (2, 3)).contains("a"), true)
+#test((1, "a", (2, 3)).contains((2, 3)), true)
+#test((1, 2, 3).contains(2.0), true)
+#test((1, 2, 3).contains(4), false)
+#test(().contains(none), false)
+
+--- array-find ---
+// Test the `find` method.
+#test((1, 7, 4, -3, 2).find(calc.even), 4)
+#test((1, 7, 4, -3, 2).find(x => x < 0), -3)
+#test((1, 3, 5).find(calc.even), none)
+#test(().find(x => true), none)
+
+--- array-find-error ---
+// Error: 22-35 panicked with: "boom"
+#(1, 2, 3).find(x => panic("boom"))
+
+--- array-position-error ---
+// Error: 26-39 panicked with: "boom"
+#(1, 2, 3).position(x => panic("boom"))
+
 --- array-position ---
 // Test the `position` method.
 #test(("Hi", "❤️", "Love").position(s => s == "❤️"), 1)
@@ -248,6 +309,33 @@
 #test((1, 2, 3, 4).filter(calc.even), (2, 4))
 #test((7, 3, 2, 5, 1).filter(x => x < 5), (3, 2, 1))
 
+--- array-partition ---
+#test(range(6).partition(calc.even), ((0, 2, 4), (1, 3, 5)))
+#test((1, 2, 3).partition(x => x > 5), ((), (1, 2, 3)))
+#test(().partition(x => true), ((), ()))
+
+--- array-partition-error ---
+// Error: 24-31 cannot add integer and string
+#(1, 2).partition(x => x + "a" > 1)
+
+--- array-group-by ---
+#let people = (
+  (name: "Ana", age: 31),
+  (name: "Ben", age: 19),
+  (name: "Cleo", age: 34),
+  (name: "Dev", age: 17),
+)
+#let groups = people.group-by(p => if p.age >= 30 { "thirties" } else { "teens" })
+#test(groups.keys(), ("thirties", "teens"))
+#test(groups.thirties.map(p => p.name), ("Ana", "Cleo"))
+#test(groups.teens.map(p => p.name), ("Ben", "Dev"))
+#test((1, 2, 3, 4).group-by(calc.even), ("false": (1, 3), "true": (2, 4)))
+#test(().group-by(x => x), (:))
+
+--- array-group-by-error ---
+// Error: 23-30 cannot add integer and string
+#(1, 2).group-by(x => x + "a")
+
 --- array-map ---
 // Test the `map` method.
 #test(().map(x => x * 2), ())
@@ -258,6 +346,11 @@
 #test(().fold("hi", grid), "hi")
 #test((1, 2, 3, 4).fold(0, (s, x) => s + x), 10)
 
+--- array-flatten-deep ---
+#let nested = (1,)
+#for i in range(2, 1001) { nested = (nested, i) }
+#test(nested.flatten(), range(1, 1001))
+
 --- array-fold-closure-without-params ---
 // Error: 20-22 unexpected argument
 #(1, 2, 3).fold(0, () => none)
@@ -269,8 +362,11 @@
 #test((1, 2, 3).sum(), 6)
 
 --- array-sum-empty ---
-// Error: 2-10 cannot calculate sum of empty array with no default
-#().sum()
+#test(().sum(), 0)
+
+--- array-sum-bad-type ---
+// Error: 2-19 cannot add element at index 2: cannot add integer and string
+#(1, 2, "3").sum()
 
 --- array-product ---
 // Test the `product` method.
@@ -279,13 +375,51 @@
 #test(([ab], 3).product(), [ab]*3)
 #test((1, 2, 3).product(), 6)
 
+--- array-mean ---
+// Test the `mean` method.
+#test((1, 2, 3, 4).mean(), 2.5)
+#test((1, 2.5, 3).mean(), 13 / 6)
+#test((4, 2).mean(), 3.0)
+#test((1pt, 2pt, 6pt).mean(), 3pt)
+#test((decimal("1.5"), 2).mean(), decimal("1.75"))
+
+--- array-mean-empty ---
+// Error: 2-11 cannot calculate mean of empty array
+#().mean()
+
+--- array-mean-bad-type ---
+// Error: 2-20 cannot average value of type string at index 1
+#(1, "2", 3).mean()
+
+--- array-mean-mixed-units ---
+// Error: 2-17 cannot add element at index 1: cannot add length and integer
+#(1pt, 2).mean()
+
+--- array-mean-bad-type-first ---
+// Error: 2-20 cannot average value of type string at index 0
+#("1", 2, 3).mean()
+
 --- array-product-empty ---
-// Error: 2-14 cannot calculate product of empty array with no default
-#().product()
+#test(().product(), 1)
+
+--- array-product-bad-type ---
+// Error: 2-25 cannot multiply element at index 1: cannot multiply length with length
+#(1pt, 2pt, 3).product()
 
 --- array-rev ---
 // Test the `rev` method.
 #test(range(3).rev(), (2, 1, 0))
+#test(().rev(), ())
+#test((1,).rev(), (1,))
+
+--- array-rev-and-sorted-do-not-mutate ---
+// Test that `rev` and `sorted` return new arrays.
+#{
+  let array = (3, 1, 2)
+  test(array.rev(), (2, 1, 3))
+  test(array.sorted(), (1, 2, 3))
+  test(array, (3, 1, 2))
+}
 
 --- array-join ---
 // Test the `join` method.
@@ -337,6 +471,7 @@
 // Test the `windows` method.
 #test(().windows(5), ())
 #test((1, 2, 3).windows(5), ())
+#test((1, 2, 3, 4, 5).windows(2), ((1, 2), (2, 3), (3, 4), (4, 5)))
 #test((1, 2, 3, 4, 5).windows(3), ((1, 2, 3), (2, 3, 4), (3, 4, 5)))
 #test((1, 2, 3, 4, 5, 6, 7, 8).windows(5), ((1, 2, 3, 4, 5), (2, 3, 4, 5, 6), (3, 4, 5, 6, 7), (4, 5, 6, 7, 8)))
 
@@ -420,6 +555,31 @@
 #test(("Hello", "World", "Hi", "There").dedup(key: x => x.len()), ("Hello", "Hi"))
 #test(("Hello", "World", "Hi", "There").dedup(key: x => x.at(0)), ("Hello", "World", "There"))
 
+--- array-union ---
+// Test the `union` method.
+#test(().union(()), ())
+#test((1, 2, 3).union((3, 4, 1)), (1, 2, 3, 4))
+#test((1, 2).union(("a", "b")), (1, 2, "a", "b"))
+#test((1, 1, 2, 2).union((2, 3, 3)), (1, 2, 3))
+#test((1, 2).union((1.0, 2.0, 3.0)), (1, 2, 3.0))
+
+--- array-intersection ---
+// Test the `intersection` method.
+#test(().intersection((1, 2)), ())
+#test((1, 2, 3).intersection((3, 2, 5)), (2, 3))
+#test((1, 2).intersection(("a", "b")), ())
+#test((1, 1, 2, 2, 3).intersection((2, 1, 1)), (1, 2))
+#test((1, 2.0, 50%).intersection((1.0, 2, 0.5)), (1, 2.0))
+
+--- array-difference ---
+// Test the `difference` method.
+#test((1, 2).difference(()), (1, 2))
+#test((1, 2, 3).difference((3, 5)), (1, 2))
+#test((1, 2).difference(("a", "b")), (1, 2))
+#test((1, 1, 2, 2, 3).difference((3,)), (1, 2))
+#test((1, 2).difference((1, 2)), ())
+#test((1, 2, 3).difference((1.0, decimal("2"))), (3,))
+
 --- array-to-dict ---
 // Test the `to-dict` method.
 #test(().to-dict(), (:))
@@ -0,0 +1,29 @@
+// Test the boolean type and its constructor.
+
+--- bool-constructor ---
+#test(bool(true), true)
+#test(bool(false), false)
+#test(bool(none), false)
+#test(bool(0), false)
+#test(bool(-3), true)
+#test(bool(0.0), false)
+#test(bool(0.5), true)
+#test(bool(decimal("0")), false)
+#test(bool(decimal("1.5")), true)
+#test(bool(""), false)
+#test(bool("a"), true)
+#test(bool(()), false)
+#test(bool((0,)), true)
+#test(bool((:)), false)
+#test(bool((a: 1)), true)
+#test(bool(bytes(())), false)
+#test(bool(bytes((1,))), true)
+
+--- bool-constructor-function ---
+// Error: 2-14 cannot convert function to boolean
+#bool(x => x)
+
+--- bool-constructor-content ---
+// Error: 2-12 cannot convert content to boolean
+#bool([Hi])
+
@@ -139,12 +139,43 @@
 #test(type(dictionary(sys).at("version")), version)
 #test(dictionary(sys).at("no-crash", default: none), none)
 
+--- dict-conversion ---
+#test(dictionary((a: 1)), (a: 1))
+#test(dictionary((("a", 1), ("b", 2))), (a: 1, b: 2))
+#test(dictionary(()), (:))
+#test(dictionary(array((b: 1, a: 2))).keys(), ("b", "a"))
+
+--- dict-bad-conversion ---
+// Error: 13-15 expected dictionary, module, or array, found integer
+#dictionary(42)
+
+--- dict-bad-conversion-from-array ---
+// Error: 13-22 expected pairs of length 2, found length 1
+#dictionary((("a",),))
+
 --- dict-remove-order ---
 // Test that removal keeps order.
 #let dict = (a: 1, b: 2, c: 3, d: 4)
 #dict.remove("b")
 #test(dict.keys(), ("a", "c", "d"))
 
+--- dict-insertion-order ---
+// Test that iteration follows insertion order.
+#let dict = (c: 1, a: 2)
+#dict.insert("b", 3)
+#dict.insert("c", 4)
+#test(dict.keys(), ("c", "a", "b"))
+#test(dict.values(), (4, 2, 3))
+#test(for (k, v) in dict { (k,) }, ("c", "a", "b"))
+
+--- dict-sorted ---
+#let dict = (b: 1, "B": 2, c: 3, a: 4)
+#test(dict.sorted().keys(), ("B", "a", "b", "c"))
+#test(dict.sorted().values(), (2, 4, 1, 3))
+#test(dict.keys(), ("b", "B", "c", "a"))
+#test(dict.sorted(), dict)
+#test((:).sorted(), (:))
+
 --- dict-temporary-lvalue ---
 // Error: 3-15 cannot mutate a temporary value
 #((key: "val").other = "some")
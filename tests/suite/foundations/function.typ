@@ -0,0 +1,63 @@
+// Test function methods.
+
+--- function-compose ---
+#let double(x) = 2 * x
+#let inc(x) = x + 1
+#let square(x) = x * x
+#test(double.compose(inc)(3), 8)
+#test(inc.compose(double)(3), 7)
+#test(square.compose(inc, double)(3), 49)
+#test(function.compose(inc, double, square)(3), 19)
+#test(repr.compose(calc.abs)(-5), "5")
+
+--- function-compose-single ---
+#let inc(x) = x + 1
+#test(inc.compose()(1), 2)
+#test(function.compose(inc)(1), 2)
+
+--- function-compose-not-callable ---
+// Error: 27-28 expected function, found integer
+#let f = calc.abs.compose(1)
+
+--- function-apply ---
+#let add(x, y) = x + y
+#test(add.apply((1, 2)), 3)
+#test(function.apply(add, ("a", "b")), "ab")
+#test(calc.max.apply((3, 7, 5)), 7)
+#test((() => 1).apply(()), 1)
+
+--- function-apply-error ---
+// Error: 18-23 cannot add integer and string
+#let add(x, y) = x + y
+#add.apply((1, "a"))
+
+--- function-apply-not-array ---
+// Error: 17-18 expected array, found integer
+#calc.abs.apply(1)
+
+--- function-with-self ---
+// Method-like closures in dictionaries receive the dictionary explicitly.
+#let counter = (
+  count: 2,
+  step: 3,
+  next: self => self.count + self.step,
+)
+#test((counter.next)(counter), 5)
+#let next = (counter.next).with(counter)
+#test(next(), 5)
+
+--- function-equality ---
+#let f(x) = x
+#let g(x) = x
+#test(rect == rect, true)
+#test(rect == circle, false)
+#test(f == f, true)
+#test(f == g, false)
+#test(f.with(1) == f.with(1), true)
+#test(f.with(1) == f.with(2), false)
+
+--- function-dedup ---
+#let f(x) = x
+#test((rect, f, rect, x => x, x => x, f).dedup().len(), 4)
+#test(range(3).map(i => x => x + i).dedup().len(), 3)
+#test(range(3).map(i => x => x).dedup().len(), 1)
@@ -88,3 +88,44 @@ sequence(
   gradient.linear(blue, red, space: rgb, angle: 45deg),
   `gradient.linear(angle: 45deg, space: rgb, (rgb("#0074d9"), 0%), (rgb("#ff4136"), 100%))`,
 )
+
+--- repr-pretty ---
+#let data = (
+  name: "Typst",
+  tags: ("fast", "fun"),
+  "two words": (level: 2, empty: (), nothing: (:)),
+)
+#test(pretty(data), ```
+(
+  name: "Typst",
+  tags: (
+    "fast",
+    "fun",
+  ),
+  "two words": (
+    level: 2,
+    empty: (),
+    nothing: (:),
+  ),
+)```.text)
+#test(pretty((1,), indent: 4), "(\n    1,\n)")
+
+--- repr-pretty-scalars ---
+#test(pretty(1), "1")
+#test(pretty("hi"), repr("hi"))
+#test(pretty(1pt + 2em), repr(1pt + 2em))
+#test(pretty(()), "()")
+
+--- inspect ---
+// Warning: 7-17 inspected value: 5
+#test(inspect(5), 5)
+
+// Warning: 7-17 inspected value: 1
+#test(inspect(1) + 2, 3)
+
+// Warning: 7-37 dict: (a: 1)
+#test(inspect((a: 1), label: "dict"), (a: 1))
+
+--- inspect-string ---
+// Warning: 7-22 inspected value: "a{b}"
+#test(inspect("a{b}"), "a{b}")
@@ -0,0 +1,48 @@
+// Test the deterministic random number generator.
+
+--- rng-same-seed ---
+#let sample(gen, n) = {
+  let values = ()
+  for _ in range(n) {
+    let (next, x) = gen.random()
+    values.push(x)
+    gen = next
+  }
+  values
+}
+
+#test(sample(rng(42), 5), sample(rng(42), 5))
+#test(sample(rng(-3), 5), sample(rng(-3), 5))
+#test(sample(rng(42), 5).all(x => 0 <= x and x < 1), true)
+
+--- rng-different-seeds ---
+#let (_, a) = rng(1).random()
+#let (_, b) = rng(2).random()
+#test(a != b, true)
+
+--- rng-random-int ---
+#let gen = rng(7)
+#let rolls = ()
+#for _ in range(50) {
+  let (next, roll) = gen.random-int(1, 7)
+  rolls.push(roll)
+  gen = next
+}
+#test(rolls.all(x => type(x) == int and 1 <= x and x < 7), true)
+#test(rolls.dedup().len() > 1, true)
+#test(rng(0).random-int(5, 6).last(), 5)
+
+--- rng-random-int-empty-range ---
+// Error: 2-25 range is empty (low: 5, high: 5)
+#rng(1).random-int(5, 5)
+
+--- rng-shuffle ---
+#let (_, a) = rng(3).shuffle(range(10))
+#let (_, b) = rng(3).shuffle(range(10))
+#test(a, b)
+#test(a.sorted(), range(10))
+#test(rng(3).shuffle(()).last(), ())
+
+--- rng-repr ---
+#test(repr(rng(5)), "rng(5)")
+#test(type(rng(5)), rng)
@@ -335,6 +335,31 @@
 #test("abc".rev(), "cba")
 #test("ax̂e".rev(), "ex̂a")
 
+--- string-format ---
+// Test the `format` method.
+#test("{} + {} = {}".format(1, 2, 1 + 2), "1 + 2 = 3")
+#test("{}, {}!".format("Hello", sym.alpha), "Hello, α!")
+#test("{1}, {0}! {}".format("World", "Hello"), "Hello, World! World")
+#test("{} {}".format(1.5, (a: none)), "1.5 (a: none)")
+#test("{{}} {{{}}}".format(1), "{} {1}")
+#test("no placeholders".format(1, 2), "no placeholders")
+#test(str.format("{}", 1), "1")
+
+--- string-format-missing-value ---
+// Error: 2-19 missing value for placeholder 1
+#"{} {}".format(1)
+
+--- string-format-invalid-placeholder ---
+// Error: 2-17 invalid placeholder in format string
+// Hint: 2-17 placeholders are written as `{}` or `{0}`
+// Hint: 2-17 use `{{` to insert a literal brace
+#"{x}".format(1)
+
+--- string-format-unmatched-brace ---
+// Error: 2-18 unmatched closing brace in format string
+// Hint: 2-18 use `}}` to insert a literal brace
+#"a } b".format()
+
 --- string-unclosed ---
 // Error: 2-2:1 unclosed string
 #"hello\"
@@ -0,0 +1,28 @@
+// Test the `sys` module.
+
+--- sys-features ---
+// The test runner enables all in-development features.
+#test(sys.features, ("html",))
+#test(sys.supports("html"), true)
+#test(sys.features.all(sys.supports), true)
+
+--- sys-supports-unknown ---
+#test(sys.supports("time-travel"), false)
+#test(sys.supports(""), false)
+
+--- sys-position ---
+#let a = sys.position()
+#let b = sys.position()
+  #let c = sys.position()
+#test(a.column, 10)
+#test(b.line, a.line + 1)
+#test(c.line, a.line + 2)
+#test(c.column, a.column + 2)
+#test(a.path, b.path)
+#test(a.path.ends-with("sys.typ"), true)
+
+--- sys-position-in-function ---
+// The position is the one of the call to `sys.position`.
+#let where() = sys.position()
+#test(where().line, where().line)
+#test(where().column, 16)
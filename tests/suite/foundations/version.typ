@@ -45,3 +45,12 @@
 --- version-type ---
 // Test the type of `sys.version`
 #test(type(sys.version), version)
+
+--- version-require ---
+// Test `sys.require-version` with a satisfied requirement.
+#test(sys.require-version(version(0, 1)), none)
+#test(sys.require-version(sys.version), none)
+
+--- version-require-unsatisfied ---
+// Error: 2-37 this document requires Typst 99.0 or newer, but the running version is VERSION
+#sys.require-version(version(99, 0))
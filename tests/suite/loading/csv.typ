@@ -13,6 +13,31 @@
 #test(data.at(2).Weight, "150kg")
 #test(data.at(1).Species, "Tiger")
 
+--- csv-quoted-fields ---
+// Test quoted fields with embedded delimiters, quotes, and newlines.
+#let data = csv(bytes("name,note\n\"Doe, Jane\",\"says \"\"hi\"\"\"\nBob,\"two\nlines\"\n"))
+#test(data, (
+  ("name", "note"),
+  ("Doe, Jane", "says \"hi\""),
+  ("Bob", "two\nlines"),
+))
+
+--- csv-header-modes ---
+// Test that the first row is data for arrays and keys for dictionaries.
+#let text = bytes("a,b\n1,2\n")
+#test(csv(text), (("a", "b"), ("1", "2")))
+#test(csv(text, row-type: dictionary), ((a: "1", b: "2"),))
+
+--- csv-numbers ---
+// Test converting numeric fields.
+#let text = bytes("id,value,label\n1,2.5,x\n-3,1e3,inf\n")
+#test(
+  csv(text, row-type: dictionary, numbers: true),
+  ((id: 1, value: 2.5, label: "x"), (id: -3, value: 1000.0, label: "inf")),
+)
+#test(csv(text, numbers: true).at(0), ("id", "value", "label"))
+#test(csv(text).at(1), ("1", "2.5", "x"))
+
 --- csv-file-not-found ---
 // Error: 6-16 file not found (searched at tests/suite/loading/nope.csv)
 #csv("nope.csv")
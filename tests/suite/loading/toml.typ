@@ -36,6 +36,27 @@
   second: 57,
 ))
 
+--- toml-encode-round-trip ---
+// Test encoding nested tables and datetimes.
+#let data = (
+  title: "Typst",
+  authors: ("a", "b"),
+  release: (
+    date: datetime(year: 2023, month: 2, day: 1),
+    time: datetime(hour: 15, minute: 38, second: 57),
+    both: datetime(year: 2023, month: 2, day: 1, hour: 15, minute: 38, second: 57),
+  ),
+)
+#let encoded = toml.encode(data)
+#test(toml(bytes(encoded)), data)
+#test(encoded.contains("date = 2023-02-01\n"), true)
+#test(encoded.contains("time = 15:38:57\n"), true)
+#test(encoded.contains("both = 2023-02-01T15:38:57\n"), true)
+
+--- toml-encode-year-out-of-range ---
+// Error: 14-58 failed to encode value as TOML (year -1 is out of range, TOML supports years 0 to 9999)
+#toml.encode((date: datetime(year: -1, month: 1, day: 1)))
+
 --- toml-invalid ---
 // Error: 7-30 failed to parse TOML (expected `.`, `=` at line 1 column 16)
 #toml("/assets/data/bad.toml")
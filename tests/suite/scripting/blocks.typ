@@ -181,3 +181,12 @@
 
 // Error: 2-3 unknown variable: x
 #x
+
+--- code-block-shadow-std-function ---
+// Overriding a standard library function is scoped to the block.
+#{
+  let upper(text) = "custom " + text
+  test(upper("hi"), "custom hi")
+  test(std.upper("hi"), "HI")
+}
+#test(upper("hi"), "HI")
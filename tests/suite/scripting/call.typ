@@ -68,6 +68,18 @@
 // Error: 2-6 expected function, found content
 #f[1](2)
 
+--- call-args-missing-closure ---
+// A missing argument is reported at the call site.
+#let f(x, y) = x + y
+
+// Error: 2-6 missing argument: y
+#f(1)
+
+--- call-args-mistyped-native ---
+// A mistyped argument is reported at the argument itself.
+// Error: 8-11 expected integer, found string
+#range("5")
+
 --- call-args-trailing-comma ---
 // Trailing comma.
 #test(1 + 1, 2,)
@@ -27,6 +27,14 @@
   test(b, 1)
 }
 
+--- import-items-only-requested ---
+// Only the listed items are bound.
+#import "module.typ": b
+#test(b, 1)
+
+// Error: 2-6 unknown variable: item
+#item
+
 --- import-wildcard-in-markup ---
 // A wildcard import.
 #import "module.typ": *
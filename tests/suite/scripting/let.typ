@@ -14,6 +14,49 @@
 #let f(body) = rect(width: 2cm, fill: fill, inset: 5pt, body)
 #f[Hi!]
 
+--- let-annotation ---
+// Test type annotations.
+#let x: int = 1 + 2
+#test(x, 3)
+#let (a, b): array = (1, 2)
+#test(a + b, 3)
+#let c: content = [Hi]
+#test(c, [Hi])
+
+// Without annotation, any type is accepted.
+#let y = "a"
+#test(y, "a")
+
+--- let-annotation-cast ---
+// Values that can be cast to the annotated type are converted.
+#let x: float = 1
+#test(type(x), float)
+#test(x, 1.0)
+#let r: relative = 50%
+#test(type(r), relative)
+
+--- let-annotation-cast-mismatch ---
+// Error: 17-22 expected float, found string
+#let x: float = "1.5"
+
+--- let-annotation-markup ---
+// In markup, a colon after the pattern starts an annotation instead of
+// ending the binding and leaving the rest as text.
+#let x: str = "a" b
+#test(x, "a")
+
+--- let-annotation-markup-text ---
+// Error: 9-14 unknown variable: Title
+#let x: Title
+
+--- let-annotation-mismatch ---
+// Error: 15-18 expected integer, found string
+#let x: int = "a"
+
+--- let-annotation-not-a-type ---
+// Error: 9-10 expected type, found integer
+#let x: 1 = 1
+
 --- let-termination ---
 // Termination.
 
@@ -17,6 +17,21 @@
 #test(var, 21)
 #test(error, false)
 
+--- loop-break-nested ---
+// Test that break only exits the innermost loop.
+#let pairs = ()
+#for i in range(3) {
+  let j = 0
+  while true {
+    if j > i { break }
+    pairs.push((i, j))
+    j += 1
+  }
+  pairs.push(i)
+}
+
+#test(pairs, ((0, 0), 0, (1, 0), (1, 1), 1, (2, 0), (2, 1), (2, 2), 2))
+
 --- loop-break-join-basic ---
 // Test joining with break.
 
@@ -140,3 +155,13 @@
     for _ in range(3) [B]
   )
 }
+
+--- loop-warning-deduplicated ---
+// A warning raised in every iteration is reported once.
+// Warning: 36-42 `csv.decode` is deprecated, directly pass bytes to `csv` instead
+#for _ in range(100) { let _ = csv.decode }
+
+--- loop-error-stops-evaluation ---
+// An error ends the loop, so it is only reported once.
+// Error: 24-31 cannot add integer and string
+#for _ in range(100) { 1 + "a" }
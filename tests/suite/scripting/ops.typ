@@ -113,6 +113,41 @@
   }
 }
 
+--- ops-binary-numeric-promotion ---
+// Integers stay integers, mixing with floats promotes to float.
+#for (a, b) in ((3, 2), (3, 2.0), (3.0, 2), (3.0, 2.0)) {
+  let expected = if type(a) == int and type(b) == int { int } else { float }
+  test(type(a + b), expected)
+  test(type(a - b), expected)
+  test(type(a * b), expected)
+  test(a + b, 5)
+  test(a - b, 1)
+  test(a * b, 6)
+}
+
+// Division always produces a float, even if it is exact.
+#test(type(4 / 2), float)
+#test(4 / 2, 2.0)
+#test(3 / 2, 1.5)
+#test(3 / 2.0, 1.5)
+#test(3.0 / 2, 1.5)
+
+// Mixing with decimals promotes to decimal.
+#test(decimal("1.5") + 1, decimal("2.5"))
+#test(1 - decimal("1.5"), decimal("-0.5"))
+#test(type(decimal("4") / 2), decimal)
+
+// Comparisons promote the same way.
+#test(1 == 1.0, true)
+#test(1 < 1.5, true)
+#test(2.0 >= 2, true)
+#test(1 == decimal("1"), true)
+#test(decimal("0.5") < 1, true)
+
+--- ops-binary-decimal-float-no-promotion ---
+// Error: 3-21 cannot add decimal and float
+#(decimal("1") + 1.0)
+
 --- ops-binary-decimal ---
 // Addition.
 #test(decimal("40.1") + decimal("13.2"), decimal("53.3"))
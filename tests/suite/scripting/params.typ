@@ -3,6 +3,18 @@
 #let f(a: 10) = a() + 1
 #f(a: _ => 5)
 
+--- params-named-only ---
+// Parameters with a default value can only be passed by name.
+#let f(x, verbose: false) = if verbose { "x = " + str(x) } else { x }
+#test(f(1, verbose: true), "x = 1")
+#test(f(1), 1)
+#test(f(verbose: false, 2), 2)
+
+--- params-named-only-passed-positionally ---
+#let f(x, verbose: false) = x
+// Error: 7-11 unexpected argument
+#f(1, true)
+
 --- params-sink-named ---
 // ... but this was.
 #let f(..x) = {}
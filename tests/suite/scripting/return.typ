@@ -28,6 +28,22 @@
 #test(f(1), "ac")
 #test(f(2), "ad")
 
+--- return-from-nested-loop ---
+// Test that return exits the whole function, not just the loops.
+#let find(matrix, target) = {
+  for (i, row) in matrix.enumerate() {
+    for (j, value) in row.enumerate() {
+      if value == target {
+        return (i, j)
+      }
+    }
+  }
+  none
+}
+
+#test(find(((1, 2), (3, 4)), 3), (1, 0))
+#test(find(((1, 2), (3, 4)), 5), none)
+
 --- return-in-nested-content-block ---
 // Test return with joining and content.
 